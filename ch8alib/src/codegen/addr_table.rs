@@ -23,14 +23,17 @@
 //usage statements
 use std::collections::HashMap;
 use super::super::error::AddrError;
+use super::super::util::constants;
 
 /// Manages label-to-address relationships
+#[derive(Clone)]
 pub struct AddrTable {
     /// The number of entries in the table
     size: u32,
 
-    /// The table data
-    data: HashMap<String, u16>
+    /// The table data, keyed by uppercased label and holding
+    /// each label as it was written along with its address
+    data: HashMap<String, (String, u16)>
 }
 
 //implementation
@@ -57,13 +60,17 @@ impl AddrTable {
     }
 
     /// Adds an entry to the table
+    ///
+    /// Labels are matched without regard to case, but the
+    /// spelling given here is kept for display
     /// 
     /// # Arguments
     ///
     /// * `label` - The label to add to the table
     /// * `addr` - The address corresponding to the label
     pub fn add_entry(&mut self, label: &str, addr: u16) {
-        self.data.insert(String::from(label), addr);
+        self.data.insert(label.to_ascii_uppercase(),
+                            (String::from(label), addr));
         self.size += 1;
     }
 
@@ -77,7 +84,7 @@ impl AddrTable {
     ///
     /// Whether the given label exists in the table
     pub fn has_entry(&self, label: &str) -> bool {
-        return self.data.contains_key(label);
+        return self.data.contains_key(&label.to_ascii_uppercase());
     }
 
     /// Gets the address for a given label
@@ -92,11 +99,57 @@ impl AddrTable {
     /// `Err(AddrError)` otherwise
     pub fn get_entry(&self, label: &str) -> Result<u16, AddrError> {
         //get the address for the label
-        return match self.data.get(label) {
-            Some(l) => Ok(*l),
+        return match self.data.get(&label.to_ascii_uppercase()) {
+            Some(l) => Ok(l.1),
             None => Err(AddrError::new(label))
         };
     }
+
+    /// Gets the name a label is stored under in the table
+    ///
+    /// Local labels (which start with a period) are scoped to the
+    /// global label defined before them, so that each global label
+    /// can have its own local labels with the same names
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The global label that encloses the label
+    /// * `label` - The label to get the name for
+    ///
+    /// # Returns
+    ///
+    /// The name of the label in the table
+    pub fn scoped_name(scope: &str, label: &str) -> String {
+        if label.starts_with(constants::LOCAL_LABEL_CHAR) {
+            return format!("{}{}", scope, label);
+        } else {
+            return String::from(label);
+        }
+    }
+
+    /// Iterates over the entries in the table
+    ///
+    /// # Returns
+    ///
+    /// An iterator over `(label, address)` pairs with each label
+    /// spelled as it was defined, sorted by address and then by label
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u16)> {
+        //collect the entries
+        let mut entries: Vec<(&str, u16)> = self.data.values()
+                                    .map(|(l, a)| (l.as_str(), *a))
+                                    .collect();
+
+        //and sort them
+        entries.sort_by(|a, b| (a.1, a.0).cmp(&(b.1, b.0)));
+        return entries.into_iter();
+    }
+}
+
+//Default implementation
+impl Default for AddrTable {
+    fn default() -> Self {
+        return AddrTable::new();
+    }
 }
 
 //unit tests
@@ -123,13 +176,88 @@ mod tests {
         assert_eq!(addr, 0x0FFF);
     }
 
+    //this test checks that a cloned table keeps its entries
+    #[test]
+    fn test_clone() {
+        let mut t = AddrTable::new();
+        t.add_entry("_start", 0x0200);
+        t.add_entry("_Sprite", 0x0300);
+        let mut c = t.clone();
+        assert_eq!(c.get_size(), 2);
+        assert_eq!(c.get_entry("_START").unwrap(), 0x0200);
+        assert_eq!(c.get_entry("_sprite").unwrap(), 0x0300);
+        let mut names: Vec<&str> = c.iter().map(|(l, _)| l).collect();
+        names.sort();
+        assert_eq!(names, vec!["_Sprite", "_start"]);
+
+        //the copy is independent of the original
+        c.add_entry("_end", 0x0400);
+        assert!(!t.has_entry("_end"));
+    }
+
     //this test checks the has_entry method
     #[test]
     fn test_has_entry() {
         let mut t = AddrTable::new();
-        assert_eq!(t.has_entry("_LABEL"), false);
+        assert!(!t.has_entry("_LABEL"));
         t.add_entry("_LABEL", 0x0FFF);
-        assert_eq!(t.has_entry("_LABEL"), true);
+        assert!(t.has_entry("_LABEL"));
+    }
+
+    //this test checks that labels keep their spelling
+    #[test]
+    fn test_label_case() {
+        let mut t = AddrTable::new();
+        t.add_entry("_Start", 0x0200);
+        assert!(t.has_entry("_START"));
+        assert_eq!(t.get_entry("_start").unwrap(), 0x0200);
+        let entries: Vec<(&str, u16)> = t.iter().collect();
+        assert_eq!(entries, vec![("_Start", 0x0200)]);
+        assert_eq!(format!("{}", t.get_entry("_strt").err().unwrap()),
+                   "Could not get the address of the label _strt");
+    }
+
+    //this test checks scoping local labels
+    #[test]
+    fn test_scoped_name() {
+        assert_eq!(AddrTable::scoped_name("_DRAW", ".LOOP"), "_DRAW.LOOP");
+        assert_eq!(AddrTable::scoped_name("_DRAW", "_ERASE"), "_ERASE");
+    }
+
+    //this test checks iterating over the entries
+    #[test]
+    fn test_iter() {
+        let mut t = AddrTable::new();
+        t.add_entry("_END", 0x0208);
+        t.add_entry("_START", 0x0200);
+        t.add_entry("_LOOP", 0x0200);
+        let entries: Vec<(&str, u16)> = t.iter().collect();
+        assert_eq!(entries, vec![("_LOOP", 0x0200), ("_START", 0x0200),
+                                 ("_END", 0x0208)]);
+    }
+
+    //this test checks that the iteration order does not
+    //depend on the order the labels were added in
+    #[test]
+    fn test_iter_order() {
+        let labels = [("_main", 0x0200), ("_main.loop", 0x0204),
+                      ("_Draw", 0x0210), ("_data", 0x0210),
+                      ("_draw.loop", 0x0212), ("_end", 0x0220)];
+        let mut fwd = AddrTable::new();
+        for (l, a) in labels.iter() {
+            fwd.add_entry(l, *a);
+        }
+        let mut rev = AddrTable::new();
+        for (l, a) in labels.iter().rev() {
+            rev.add_entry(l, *a);
+        }
+
+        //both tables list the labels by address and then by name
+        let expected = vec![("_main", 0x0200), ("_main.loop", 0x0204),
+                            ("_Draw", 0x0210), ("_data", 0x0210),
+                            ("_draw.loop", 0x0212), ("_end", 0x0220)];
+        assert_eq!(fwd.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(rev.iter().collect::<Vec<_>>(), expected);
     }
 }
 
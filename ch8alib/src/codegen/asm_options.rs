@@ -0,0 +1,174 @@
+/*
+ * asm_options.rs
+ * Defines a struct that holds options for assembling code
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use super::super::util::constants;
+use super::super::util::Sigils;
+
+/// Options that control how source code is assembled
+#[derive(Clone)]
+pub struct AsmOptions {
+    /// The address the program is loaded at
+    origin: u16,
+
+    /// Whether tokens that cannot start a statement are errors
+    strict: bool,
+
+    /// The markers that start comments and literals
+    sigils: Sigils,
+
+    /// Whether label addresses are found by preprocessing
+    /// the code before it is assembled
+    preprocess: bool
+}
+
+//implementation
+impl AsmOptions {
+    /// Constructs a new `AsmOptions` instance
+    /// with the default options
+    ///
+    /// # Returns
+    ///
+    /// A new `AsmOptions` instance that loads
+    /// programs at `constants::MEM_START`
+    pub fn new() -> Self {
+        return AsmOptions {
+            origin: constants::MEM_START,
+            strict: false,
+            sigils: Sigils::new(),
+            preprocess: true
+        };
+    }
+
+    /// Sets the address the program is loaded at
+    ///
+    /// # Argument
+    ///
+    /// * `new_origin` - The new load address
+    ///
+    /// # Returns
+    ///
+    /// The options with the new origin
+    pub fn with_origin(mut self, new_origin: u16) -> Self {
+        self.origin = new_origin;
+        return self;
+    }
+
+    /// Sets whether tokens that cannot start a statement,
+    /// such as a stray comma or register, are errors
+    ///
+    /// # Argument
+    ///
+    /// * `new_strict` - Whether to assemble in strict mode
+    ///
+    /// # Returns
+    ///
+    /// The options with the new strictness
+    pub fn with_strict(mut self, new_strict: bool) -> Self {
+        self.strict = new_strict;
+        return self;
+    }
+
+    /// Sets the markers that start comments and literals,
+    /// for assembling source written in another dialect
+    ///
+    /// The markers are checked when an `Assembler` is created
+    /// with the options, which fails if any of them collide.
+    ///
+    /// # Argument
+    ///
+    /// * `new_sigils` - The new comment and literal markers
+    ///
+    /// # Returns
+    ///
+    /// The options with the new markers
+    pub fn with_sigils(mut self, new_sigils: &Sigils) -> Self {
+        self.sigils = new_sigils.clone();
+        return self;
+    }
+
+    /// Sets whether the code is preprocessed to find the addresses of
+    /// labels before it is assembled
+    ///
+    /// Without preprocessing, each label is given its address when its
+    /// definition is assembled, so any reference to a label that is
+    /// defined later is an error. This is a debugging aid for telling
+    /// whether a wrong address comes from the preprocessor's sizing
+    /// or from code generation.
+    ///
+    /// # Argument
+    ///
+    /// * `new_preprocess` - Whether to preprocess the code
+    ///
+    /// # Returns
+    ///
+    /// The options with the new preprocessing setting
+    pub fn with_preprocess(mut self, new_preprocess: bool) -> Self {
+        self.preprocess = new_preprocess;
+        return self;
+    }
+
+    /// Gets whether the code is preprocessed before it is assembled
+    ///
+    /// # Returns
+    ///
+    /// Whether label addresses are found by the preprocessor
+    pub fn is_preprocessed(&self) -> bool {
+        return self.preprocess;
+    }
+
+    /// Gets the markers that start comments and literals
+    ///
+    /// # Returns
+    ///
+    /// A reference to the comment and literal markers
+    pub fn get_sigils(&self) -> &Sigils {
+        return &self.sigils;
+    }
+
+    /// Gets whether tokens that cannot start a statement are errors
+    ///
+    /// # Returns
+    ///
+    /// Whether the options assemble in strict mode
+    pub fn is_strict(&self) -> bool {
+        return self.strict;
+    }
+
+    /// Gets the address the program is loaded at
+    ///
+    /// # Returns
+    ///
+    /// The load address of the program
+    pub fn get_origin(&self) -> u16 {
+        return self.origin;
+    }
+}
+
+//Default implementation
+impl Default for AsmOptions {
+    fn default() -> Self {
+        return AsmOptions::new();
+    }
+}
+
+//end of file
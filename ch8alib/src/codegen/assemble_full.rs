@@ -0,0 +1,74 @@
+/*
+ * assemble_full.rs
+ * Defines a function that assembles code into bytes and symbols
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use super::Assembler;
+use super::AsmOptions;
+use super::AddrTable;
+use super::super::error::AsmError;
+
+/// Assembles source code in one call, returning both
+/// the assembled bytes and the table of label addresses
+///
+/// # Argument
+///
+/// * `code` - The source code to be assembled
+///
+/// # Returns
+///
+/// The assembled bytes and the label table, wrapped in a `Result`
+pub fn assemble_full(code: &str) -> Result<(Vec<u8>, AddrTable), AsmError> {
+    //create the assembler (the binary is never written,
+    //so it needs no name)
+    let mut asm = Assembler::unnamed(code, &AsmOptions::new())?;
+
+    //assemble the code
+    asm.assemble()?;
+
+    //and return the bytes and the labels
+    return Ok((asm.get_bytes().to_vec(), asm.get_addrs().clone()));
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the assemble_full function
+    use super::*;
+    use super::super::super::util::constants;
+
+    //this test checks assembling code into bytes and symbols
+    #[test]
+    fn test_assemble_full() {
+        let code = "_start:\nCLS\n_loop:\nJMP _loop";
+        let (bytes, addrs) = assemble_full(code).unwrap();
+        assert_eq!(bytes, vec![0x00, 0xE0, 0x12, 0x02]);
+        assert_eq!(addrs.get_size(), 2);
+        assert_eq!(addrs.get_entry("_start").unwrap(), constants::MEM_START);
+        assert_eq!(addrs.get_entry("_loop").unwrap(),
+                   constants::MEM_START + 2);
+
+        //errors are passed through
+        assert!(assemble_full("_start:\nJMP _nowhere").is_err());
+    }
+}
+
+//end of file
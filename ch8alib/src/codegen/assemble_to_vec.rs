@@ -0,0 +1,72 @@
+/*
+ * assemble_to_vec.rs
+ * Defines a function that assembles source code into a byte vector
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use super::Assembler;
+use super::AsmOptions;
+use super::super::error::AsmError;
+
+/// Assembles source code in one call, returning the assembled bytes
+///
+/// The bytes are the same ones `ch8asm` writes to a `.c8` file, so
+/// they can be compared against a stored binary to catch changes
+/// in the assembler's output
+///
+/// # Argument
+///
+/// * `code` - The source code to be assembled
+///
+/// # Returns
+///
+/// The assembled bytes, wrapped in a `Result`
+pub fn assemble_to_vec(code: &str) -> Result<Vec<u8>, AsmError> {
+    //create the assembler (the binary is never written,
+    //so it needs no name)
+    let mut asm = Assembler::unnamed(code, &AsmOptions::new())?;
+
+    //assemble the code
+    asm.assemble()?;
+
+    //and return the bytes
+    return Ok(asm.get_bytes().to_vec());
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the assemble_to_vec function
+    use super::*;
+
+    //this test checks assembling code into bytes
+    #[test]
+    fn test_assemble_to_vec() {
+        let code = "_start:\nCLS\nDB #1\nALIGN\nJMP _start";
+        assert_eq!(assemble_to_vec(code).unwrap(),
+                   vec![0x00, 0xE0, 0x01, 0x00, 0x12, 0x00]);
+        assert!(assemble_to_vec("").unwrap().is_empty());
+
+        //errors are passed through
+        assert!(assemble_to_vec("_start:\nJMP _nowhere").is_err());
+    }
+}
+
+//end of file
@@ -0,0 +1,86 @@
+/*
+ * assemble_web.rs
+ * Defines a function that assembles source code for web front ends
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use super::Assembler;
+use super::AsmOptions;
+
+/// Assembles source code in one call, describing any errors as text
+///
+/// This never touches the filesystem or the environment, so it can be
+/// wrapped for WebAssembly or other bindings that cannot pass Rust
+/// error types across
+///
+/// # Argument
+///
+/// * `code` - The source code to be assembled
+///
+/// # Returns
+///
+/// The assembled bytes, or every error that was found
+/// as it would be printed, one after another
+pub fn assemble_web(code: &str) -> Result<Vec<u8>, String> {
+    //create the assembler (the binary is never written,
+    //so it needs no name)
+    let mut asm = match Assembler::unnamed(code, &AsmOptions::new()) {
+        Ok(a) => a,
+        Err(e) => return Err(e.to_string())
+    };
+
+    //assemble the code, collecting the errors
+    if let Err(errs) = asm.assemble_all() {
+        let msgs: Vec<String> = errs.iter().map(|e| e.to_string())
+                                    .collect();
+        return Err(msgs.join("\n"));
+    }
+
+    //and return the bytes
+    return Ok(asm.get_bytes().to_vec());
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the assemble_web function
+    use super::*;
+
+    //this test checks assembling code with text errors
+    #[test]
+    fn test_assemble_web() {
+        assert_eq!(assemble_web("_start:\nCLS\nJMP _start").unwrap(),
+                   vec![0x00, 0xE0, 0x12, 0x00]);
+
+        //errors are described as they would be printed
+        let err = assemble_web("CLS\nFOO V1\nMOV V0 #1").err().unwrap();
+        let lines: Vec<&str> = err.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].starts_with("(2:1): "));
+        assert_eq!(lines[1].trim(), "FOO V1");
+        assert!(lines[3].starts_with("(3:8): "));
+
+        //including errors found while preprocessing
+        let err = assemble_web("CLS\n;{ never closed").err().unwrap();
+        assert!(err.starts_with("(2:1): unterminated block comment"));
+    }
+}
+
+//end of file
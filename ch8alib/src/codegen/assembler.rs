@@ -24,18 +24,44 @@
 extern crate ch8_isa;
 
 //usage statements
+use std::mem;
+use std::convert::TryFrom;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use super::AddrTable;
+use super::RegTable;
+use super::SectionMap;
 use super::Preprocessor;
+use super::Listing;
+use super::AsmOptions;
+use super::Instr;
+use super::decode_opcode;
+use super::encode_instr;
 use super::super::lex::AsmLexer;
 use super::super::lex::Token;
 use super::super::lex::TokenType;
 use super::super::error::AsmError;
 use super::super::error::ParseError;
+use super::super::error::AddrError;
 use super::super::error::OpcodeError;
 use super::super::error::ArgError;
 use super::super::error::SkipError;
+use super::super::error::CondError;
+use super::super::error::OverflowError;
+use super::super::error::AliasError;
+use super::super::error::ExprError;
+use super::super::error::RangeError;
+use super::super::error::StrayError;
+use super::super::error::RegError;
+use super::super::error::AsmWarning;
+use super::super::util::constants;
+use super::super::util::Variant;
+use super::super::util::Position;
+use super::super::util::decode_source;
+use super::super::util::fits_word;
 use ch8_isa::codegen::Binary;
-use ch8_isa::codegen::Instruction;
+use ch8_isa::error::BinaryError;
+use ch8_isa::error::BinaryErrorType;
 use ch8_isa::data;
 
 /// Assembles Chip-8 binaries
@@ -46,11 +72,66 @@ pub struct Assembler {
     /// The address table for labels in the source code
     addrs: AddrTable,
 
+    /// The register aliases defined in the source code
+    aliases: RegTable,
+
+    /// The code and data sections marked in the source code
+    sections: SectionMap,
+
+    /// The global label that local labels are scoped to
+    scope: String,
+
     /// The current `Token` being assembled
     cur_token: Token,
 
-    /// The binary being created
-    binary: Binary 
+    /// The line number the current `Token` starts on
+    tok_line: u32,
+
+    /// The column number the current `Token` starts on
+    tok_col: u32,
+
+    /// The line number the most recently eaten `Token` started on
+    last_line: u32,
+
+    /// The column number the most recently eaten `Token` started on
+    last_col: u32,
+
+    /// The name of the binary being created
+    name: String,
+
+    /// A copy of the bytes written to the binary
+    bytes: Vec<u8>,
+
+    /// The listing of the assembled code
+    listing: Listing,
+
+    /// The address the program is loaded at
+    origin: u16,
+
+    /// Whether tokens that cannot start a statement are errors
+    strict: bool,
+
+    /// The options the assembler was created with
+    opts: AsmOptions,
+
+    /// The number and text of the most recent statement's line
+    src_line: (u32, String),
+
+    /// The warnings found while assembling
+    warnings: Vec<AsmWarning>,
+
+    /// The address, line, and column of each assembled instruction
+    instrs: Vec<(u16, u32, u32)>,
+
+    /// The line and column of the statement that wrote the first byte
+    entry_pos: (u32, u32),
+
+    /// The references to labels that are never defined,
+    /// found while preprocessing
+    undefined: Vec<AddrError>,
+
+    /// The lexer errors found before assembling started
+    lex_errors: Vec<AsmError>
 }
 
 //implementation
@@ -67,338 +148,1053 @@ impl Assembler {
     /// A new `Assembler` instance with the given properties,
     /// wrapped in a `Result`
     pub fn new(code: &str, name: &str) -> Result<Self, AsmError> {
-        //create the binary
-        let bin = match Binary::new(name) {
-            Ok(b) => b,
-            Err(be) => return Err(AsmError::Binary(be))
-        };
+        return Assembler::with_options(code, name, &AsmOptions::new());
+    }
+
+    /// Constructs a new `Assembler` instance with the given options
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The source code to be assembled
+    /// * `name` - The name of the assembled binary
+    /// * `opts` - The options to assemble with
+    ///
+    /// # Returns
+    ///
+    /// A new `Assembler` instance with the given properties,
+    /// or an `AsmError` if the markers in the options collide
+    pub fn with_options(code: &str, name: &str, opts: &AsmOptions)
+        -> Result<Self, AsmError> {
+        //ensure that the markers can be told apart
+        opts.get_sigils().validate()?;
+
+        //create the lexer
+        let mut lex = AsmLexer::with_sigils(code, opts.get_sigils());
 
         //create the preprocessor
-        let mut prep = match Preprocessor::new(code) {
-            Ok(p) => p,
-            Err(le) => return Err(AsmError::Lexer(le))
+        let mut prep = Preprocessor::with_sigils(code, opts.get_origin(),
+                                                 opts.get_sigils());
+
+        //preprocess the code, unless labels are to be
+        //defined as their definitions are assembled
+        //(lexer errors are kept to report when assembling)
+        let mut lex_errs = Vec::new();
+        let new_addrs = if opts.is_preprocessed() {
+            match prep.process() {
+                Ok(at) => at,
+                Err(les) => {
+                    lex_errs = les.into_iter()
+                        .map(|le| Assembler::annotate(&lex, le.into()))
+                        .collect();
+                    AddrTable::new()
+                }
+            }
+        } else {
+            AddrTable::new()
         };
 
-        //preprocess the code
-        let new_addrs = match prep.process() {
-            Ok(at) => at,
-            Err(le) => return Err(AsmError::Lexer(le))
+        //find the references to labels that were not defined
+        //(which is only meaningful if preprocessing succeeded)
+        let undef = if lex_errs.is_empty() {
+            prep.undefined_labels(&new_addrs)
+        } else {
+            Vec::new()
         };
 
-        //create the lexer
-        let mut lex = AsmLexer::new(code);
-
-        //get the first token
-        let tok = match lex.get_next_token() {
-            Ok(t) => t,
-            Err(le) => return Err(AsmError::Lexer(le))
+        //get the first token, skipping past lexer errors and the
+        //rest of their lines (the preprocessor has already found
+        //the errors, if it ran)
+        let mut err_line = 0;
+        let tok = loop {
+            match lex.get_next_token() {
+                Ok(t) => {
+                    if (lex.get_token_line() > err_line) ||
+                        (t.get_type() == TokenType::EndOfInput) ||
+                        (t.get_type() == TokenType::Separator) ||
+                        (t.get_type() == TokenType::LblDef) {
+                        break t;
+                    }
+                },
+                Err(le) => {
+                    err_line = le.get_line();
+                    if !opts.is_preprocessed() {
+                        lex_errs.push(Assembler::annotate(&lex, le.into()));
+                    }
+                }
+            };
         };
 
         //and return the instance
         return Ok(Assembler {
+            tok_line: lex.get_token_line(),
+            tok_col: lex.get_token_column(),
+            last_line: lex.get_token_line(),
+            last_col: lex.get_token_column(),
             lexer: lex,
             addrs: new_addrs,
+            aliases: prep.get_aliases().clone(),
+            sections: prep.get_sections().clone(),
+            scope: String::new(),
             cur_token: tok,
-            binary: bin 
+            name: String::from(name),
+            bytes: Vec::new(),
+            listing: Listing::new(),
+            origin: opts.get_origin(),
+            strict: opts.is_strict(),
+            opts: opts.clone(),
+            src_line: (0, String::new()),
+            warnings: Vec::new(),
+            instrs: Vec::new(),
+            entry_pos: (0, 0),
+            undefined: undef,
+            lex_errors: lex_errs
         });
     }
 
+    /// Constructs a new `Assembler` instance whose binary is not
+    /// named yet, for assembling in memory and choosing where
+    /// to write the binary later with `write_to`
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The source code to be assembled
+    /// * `opts` - The options to assemble with
+    ///
+    /// # Returns
+    ///
+    /// A new `Assembler` instance with the given properties,
+    /// wrapped in a `Result`
+    pub fn unnamed(code: &str, opts: &AsmOptions) -> Result<Self, AsmError> {
+        return Assembler::with_options(code, "", opts);
+    }
+
+    /// Resets the assembler to assemble new source code with the
+    /// same options, reusing the buffers it has already allocated
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The new source code to be assembled
+    /// * `name` - The name of the new assembled binary
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the assembler was reset, or the error found while
+    /// setting up for the new code (which leaves the assembler as it was)
+    pub fn reset(&mut self, code: &str, name: &str) -> Result<(), AsmError> {
+        //create an assembler for the new code
+        let mut fresh = Assembler::with_options(code, name, &self.opts)?;
+
+        //move the old buffers into it, emptied
+        self.bytes.clear();
+        self.instrs.clear();
+        self.warnings.clear();
+        mem::swap(&mut fresh.bytes, &mut self.bytes);
+        mem::swap(&mut fresh.instrs, &mut self.instrs);
+        mem::swap(&mut fresh.warnings, &mut self.warnings);
+
+        //and replace the old state
+        *self = fresh;
+        return Ok(());
+    }
+
+    /// Constructs a new `Assembler` instance from the raw bytes of
+    /// a source file, which need not be valid UTF-8
+    ///
+    /// Comments may hold any bytes, but any byte that is not ASCII
+    /// is an error everywhere else. This includes strings, since the
+    /// original bytes of a character in a string cannot be recovered
+    /// once the source is decoded, whether it was UTF-8 or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The bytes of the source code to be assembled
+    /// * `name` - The name of the assembled binary
+    /// * `opts` - The options to assemble with
+    ///
+    /// # Returns
+    ///
+    /// A new `Assembler` instance with the given properties,
+    /// wrapped in a `Result`
+    pub fn from_bytes(code: &[u8], name: &str, opts: &AsmOptions)
+        -> Result<Self, AsmError> {
+        return Assembler::with_options(&decode_source(code), name, opts);
+    }
+
     /// Assembles source code into a binary
     /// 
     /// # Returns
     ///
-    /// The assembled binary, wrapped in a `Result`
-    pub fn assemble(&mut self) -> Result<&mut Binary, AsmError> {
-        //loop and generate code
-        loop {
-            //check for an EOF token
-            if self.cur_token.get_type() == TokenType::EndOfInput {
-                break;
-            }
+    /// The assembled bytes, wrapped in a `Result`
+    pub fn assemble(&mut self) -> Result<&[u8], AsmError> {
+        //report a lexer error found while preprocessing
+        if let Some(e) = mem::take(&mut self.lex_errors).into_iter().next() {
+            return Err(e);
+        }
 
-            //handle different tokens
-            if self.cur_token.get_type() == TokenType::Instruction {
-                //process the instruction
-                let instr = self.instruction()?;
+        //report an undefined label before assembling anything
+        if let Some(ae) = mem::take(&mut self.undefined).into_iter().next() {
+            return Err(Assembler::annotate(&self.lexer, ae.into()));
+        }
 
-                //and add it to the binary
-                match self.binary.add_instruction(&instr) {
-                    Ok(()) => {},
-                    Err(be) => return Err(AsmError::Binary(be))
-                };
-            } else if self.cur_token.get_type() == TokenType::BinLit {
-                //get the byte
-                let b = self.bin_lit()?;
-
-                //and write it to the binary
-                match self.binary.add_byte(b) {
-                    Ok(()) => {},
-                    Err(be) => return Err(AsmError::Binary(be))
-                };
-            } else if self.cur_token.get_type() == TokenType::DecLit {
-                //get the decimal literal
-                let dec = self.dec_lit()?;
-
-                //determine what size the literal is
-                if dec > 0xFF {
-                    match self.binary.add_word(dec) {
-                        Ok(()) => {},
-                        Err(be) => return Err(AsmError::Binary(be))
-                    };
-                } else {
-                    match self.binary.add_byte(dec as u8) {
-                        Ok(()) => {},
-                        Err(be) => return Err(AsmError::Binary(be))
-                    };
-                }
-            } else if self.cur_token.get_type() == TokenType::HexLit {
-                //get the decimal literal
-                let hex = self.hex_lit()?;
-
-                //determine what size the literal is
-                if hex > 0xFF {
-                    match self.binary.add_word(hex) {
-                        Ok(()) => {},
-                        Err(be) => return Err(AsmError::Binary(be))
-                    };
-                } else {
-                    match self.binary.add_byte(hex as u8) {
-                        Ok(()) => {},
-                        Err(be) => return Err(AsmError::Binary(be))
-                    };
-                }
-            } else if self.cur_token.get_type() == TokenType::LblDef {
-                self.eat(&TokenType::LblDef)?;
+        //loop and generate code
+        while self.cur_token.get_type() != TokenType::EndOfInput {
+            if let Err(e) = self.statement() {
+                return Err(Assembler::annotate(&self.lexer, e));
             }
         }
 
-        //and return the binary
-        return Ok(&mut self.binary);
+        //ensure that the program fits in memory
+        self.check_size()?;
+
+        //and return the bytes
+        return Ok(&self.bytes);
     }
 
-    /// Verifies the current token and gets the next token
-    /// 
-    /// # Argument
+    /// Assembles source code into a binary, collecting every error
+    /// instead of stopping at the first one. After an error, assembly
+    /// resumes at the next line or label definition.
     ///
-    /// * `ttype` - The token type to verify against
+    /// Lexer errors found while preprocessing, and then references
+    /// to undefined labels, are all reported together before
+    /// anything is assembled.
     ///
     /// # Returns
     ///
-    /// `Ok` if no errors occur, `Err(ParseError)` if the token
-    /// is not verified properly, or `Err(LexerError)` if an
-    /// unknown character is found
-    fn eat(&mut self, ttype: &TokenType ) -> Result<(), AsmError> {
-        //check the type
-        if self.cur_token.get_type() == *ttype {
-            let lr = self.lexer.get_next_token();
-            return match lr {
-                Err(le) => Err(AsmError::Lexer(le)),
-                Ok(t) => {
-                    self.cur_token = t;
-                    Ok(())
+    /// The assembled bytes, or every error that was found
+    pub fn assemble_all(&mut self) -> Result<&[u8], Vec<AsmError>> {
+        //report every lexer error found while preprocessing
+        if self.opts.is_preprocessed() && !self.lex_errors.is_empty() {
+            return Err(mem::take(&mut self.lex_errors));
+        }
+
+        //report every undefined label before assembling anything
+        if !self.undefined.is_empty() {
+            let lexer = &self.lexer;
+            return Err(mem::take(&mut self.undefined).into_iter()
+                        .map(|ae| Assembler::annotate(lexer, ae.into()))
+                        .collect());
+        }
+
+        //create the error list, starting with any lexer
+        //errors found before the first token
+        let mut errs = mem::take(&mut self.lex_errors);
+
+        //loop and generate code
+        while self.cur_token.get_type() != TokenType::EndOfInput {
+            let start = self.cur_pos();
+            match self.statement() {
+                Ok(()) => {},
+                Err(e @ AsmError::Overflow(_)) => {
+                    //nothing after the end of memory can be assembled
+                    errs.push(e);
+                    break;
+                },
+                Err(e) => {
+                    errs.push(Assembler::annotate(&self.lexer, e));
+                    self.sync(start, &mut errs);
                 }
             };
+        }
+
+        //ensure that the program fits in memory
+        if let Err(e) = self.check_size() {
+            errs.push(e);
+        }
+
+        //and return the bytes or the errors
+        if errs.is_empty() {
+            return Ok(&self.bytes);
         } else {
-            return Err(AsmError::Parser(ParseError::new(
-                        ttype, &self.cur_token.get_type(),
-                        self.lexer.get_line(),
-                        self.lexer.get_column())));
+            return Err(errs);
         }
     }
 
-    /// Assembles an instruction
-    /// 
+    /// Assembles a single top-level statement
+    ///
     /// # Returns
     ///
-    /// The assembled instruction, wrapped in a `Result`
-    fn instruction(&mut self) -> Result<Instruction, AsmError> {
-        //save the current token
-        let save_token = self.cur_token.clone();
-
-        //parse the instruction
-        self.eat(&TokenType::Instruction)?;
-
-        //get the instruction string
-        let instr = save_token.get_value().as_text().unwrap();
+    /// `Ok` if the statement was assembled, `Err(AsmError)` otherwise
+    fn statement(&mut self) -> Result<(), AsmError> {
+        //save where the statement starts for the listing
+        let (start, addr) = (self.bytes.len(), self.get_address());
+        let stmt_pos = (self.tok_line, self.tok_col);
+        if self.src_line.0 != self.tok_line {
+            self.src_line = (self.tok_line,
+                             self.lexer.get_line_text(self.tok_line));
+        }
 
-        //and generate the instruction object
-        return match instr.as_str() {
-            "CLS" => Ok(Instruction::CLS),
-            "RET" => Ok(Instruction::RET), 
-            "JMP" => {
-                //get the label address
-                let addr = self.label()?;
+        //handle different tokens
+        if self.is_directive() {
+            //process the directive
+            self.directive()?;
+        } else if self.cur_token.get_type() == TokenType::Instruction {
+            //process the instruction, naming it in parse errors
+            let (line, col) = (self.tok_line, self.tok_col);
+            let name = self.cur_token.get_value_ref().as_text().unwrap();
+            let instr = match self.instruction() {
+                Err(AsmError::Parser(mut pe)) => {
+                    pe.set_instruction(&name);
+                    return Err(AsmError::Parser(pe));
+                },
+                res => res?
+            };
 
-                //create the data
-                let data = data::JmpData::new(addr);
+            //and add its encoding to the binary
+            for byte in encode_instr(&instr) {
+                self.emit_byte(byte)?;
+            }
+            self.instrs.push((addr, line, col));
+        } else if self.at_constant() {
+            //get the value of the literal or expression
+            let (val, wide) = self.expr()?;
+
+            //and write it at the size it needs
+            //(negatives are written in two's complement)
+            if wide || (val > i32::from(constants::MAX_BYTE_LIT)) ||
+                (val < constants::MIN_BYTE_LIT) {
+                self.emit_word(val as u16)?;
+            } else {
+                self.emit_byte(val as u8)?;
+            }
+        } else if self.cur_token.get_type() == TokenType::LblDef {
+            //parse the label, keeping its token
+            let save_token = self.eat(&TokenType::LblDef)?;
+
+            //start a new scope at global labels
+            let lbl = save_token.get_value_ref().as_text().unwrap();
+            if !lbl.starts_with(constants::LOCAL_LABEL_CHAR) {
+                self.scope = lbl.clone();
+            }
 
-                //and return the instruction
-                Ok(Instruction::JMP(data))
-            },
-            "CALL" => {
-                //get the label address
-                let addr = self.label()?;
+            //and define the label here if there was no preprocessing
+            if !self.opts.is_preprocessed() {
+                self.addrs.add_entry(&AddrTable::scoped_name(&self.scope,
+                                                             &lbl), addr);
+            }
+        } else if self.cur_token.get_type() == TokenType::Separator {
+            //separators only end the statement before them
+            self.eat(&TokenType::Separator)?;
+        } else if self.cur_token.get_type() == TokenType::Section {
+            //sections were recorded by the preprocessor
+            //and do not change what is assembled
+            self.eat(&TokenType::Section)?;
+        } else if self.cur_token.get_type() == TokenType::RegDef {
+            //the alias was recorded by the preprocessor,
+            //so just check that it names a register
+            let name = self.eat(&TokenType::RegDef)?;
+            let reg = self.eat(&TokenType::Register)?;
+
+            //(or record it here if there was no preprocessing)
+            if !self.opts.is_preprocessed() {
+                self.aliases.add_entry(
+                            &name.get_value_ref().as_text().unwrap(),
+                            &reg.get_value_ref().as_text().unwrap());
+            }
+        } else if self.strict {
+            //nothing else can start a statement
+            return Err(AsmError::Stray(StrayError::new(
+                        self.cur_token.get_type_ref(),
+                        Position::new(self.tok_line, self.tok_col))));
+        } else {
+            //skip anything else, so that assembly always moves on
+            let pos = self.cur_pos();
+            let ttype = self.cur_token.get_type();
+            self.eat(&ttype)?;
+            self.warn(&format!("ignoring unexpected {}", ttype), pos);
+        }
 
-                //create the data
-                let data = data::CallData::new(addr);
+        //remember the statement that starts the program
+        if (start == 0) && !self.bytes.is_empty() {
+            self.entry_pos = stmt_pos;
+        }
 
-                //and return the instruction
-                Ok(Instruction::CALL(data))
-            },
-            "SKIP" => {
-                //parse the period
-                self.eat(&TokenType::Period)?;
+        //record the statement in the listing
+        self.listing.add_entry(addr, &self.bytes[start..],
+                               &self.src_line.1);
 
-                //get the skip type
-                let st = self.skiptype()?;
+        //and return a success
+        return Ok(());
+    }
 
-                //get the first argument
-                let vx = self.register()?;
+    /// Gets the bytes assembled so far
+    ///
+    /// These are the same bytes that `write` would save, so the
+    /// assembled program can be used in memory without touching
+    /// the filesystem
+    ///
+    /// # Returns
+    ///
+    /// The bytes written to the binary, in order
+    pub fn get_bytes(&self) -> &[u8] {
+        return &self.bytes;
+    }
 
-                //ensure that VX is not the I register
-                if vx == data::Register::I {
-                    return Err(AsmError::Argument(
-                                ArgError::new(&vx, "SKIP",
-                                        self.lexer.get_line(),
-                                        self.lexer.get_column())));
-                }
+    /// Writes the bytes assembled so far to the binary named
+    /// when the assembler was constructed
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written, wrapped in a `Result`
+    pub fn write(&self) -> Result<usize, AsmError> {
+        return self.write_to(&self.name);
+    }
 
-                //parse a possible comma
-                if (st == data::SkipType::Equals) ||
-                    (st == data::SkipType::NotEquals) {
-                    self.eat(&TokenType::Comma)?;
-                }
+    /// Writes the bytes assembled so far to a new binary file
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the binary file, which must not exist
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written, wrapped in a `Result`
+    pub fn write_to(&self, name: &str) -> Result<usize, AsmError> {
+        //an unnamed binary has nowhere to go
+        if name.is_empty() {
+            return Err(AsmError::Binary(BinaryError::new(
+                        BinaryErrorType::FileError, name)));
+        }
 
-                //save the tokentype
-                let ttype = self.cur_token.get_type().clone();
+        //create the binary
+        let mut bin = Binary::new(name)?;
 
-                //and generate the instruction
-                if ttype == TokenType::Register {
-                    //parse the register
-                    let vy = self.register()?;
+        //copy the assembled bytes into it
+        for byte in &self.bytes {
+            bin.add_byte(*byte)?;
+        }
 
-                    //ensure that VY is not the I register
-                    if vy == data::Register::I {
-                        return Err(AsmError::Argument(
-                                    ArgError::new(&vy, "SKIP",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
-                    }
+        //and write it out
+        return Ok(bin.write_to_file()?);
+    }
 
-                    //ensure that the skip type is not a key variant
-                    if (st == data::SkipType::KeyUp) ||
-                        (st == data::SkipType::KeyDown) {
-                        return Err(AsmError::Skip(
-                                    SkipError::new(st,
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
-                    }
+    /// Gets the number of bytes assembled so far
+    ///
+    /// # Returns
+    ///
+    /// The length of the assembled binary in bytes
+    pub fn len(&self) -> usize {
+        return self.bytes.len();
+    }
 
-                    //create the data
-                    let data = data::SkipData::with_register(vx,
-                                                             vy, st);
+    /// Gets the number of instructions assembled so far
+    ///
+    /// # Returns
+    ///
+    /// The number of instructions in the assembled binary,
+    /// not counting data
+    pub fn get_instr_count(&self) -> usize {
+        return self.instrs.len();
+    }
 
-                    //and return it
-                    Ok(Instruction::SKIP(data))
-                } else if (ttype == TokenType::HexLit) ||
-                            (ttype == TokenType::DecLit) ||
-                            (ttype == TokenType::BinLit) {
+    /// Gets the number of bytes taken up by instructions
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes in the assembled binary that belong
+    /// to instructions (two for each, or four for a `MOVL`)
+    pub fn get_code_len(&self) -> usize {
+        return self.instrs.iter()
+                    .map(|&(addr, _, _)| usize::from(self.instr_len(addr)))
+                    .sum();
+    }
 
-                    //get the value
-                    let nn16 = self.constant()?;
-                    let nn = nn16 as u8; 
+    /// Determines whether any bytes have been assembled
+    ///
+    /// # Returns
+    ///
+    /// Whether the assembled binary is empty
+    pub fn is_empty(&self) -> bool {
+        return self.bytes.is_empty();
+    }
 
-                    //ensure that the skip type is not a key variant
-                    if (st == data::SkipType::KeyUp) ||
-                        (st == data::SkipType::KeyDown) {
-                        return Err(AsmError::Skip(
-                                    SkipError::new(st,
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
-                    }
+    /// Gets the address the program is loaded at
+    ///
+    /// # Returns
+    ///
+    /// The load address of the program
+    pub fn get_origin(&self) -> u16 {
+        return self.origin;
+    }
 
-                    //create the data
-                    let data = data::SkipData::with_constant(vx, nn, st);
+    /// Gets the address that the next assembled byte is written to
+    ///
+    /// # Returns
+    ///
+    /// The current write address of the assembler
+    pub fn get_address(&self) -> u16 {
+        //writes stop at the end of memory, so this never
+        //saturates in practice, but it must not wrap either
+        return u16::try_from(self.bytes.len()).ok()
+                .and_then(|len| self.origin.checked_add(len))
+                .unwrap_or(u16::MAX);
+    }
 
-                    //and return the instruction
-                    Ok(Instruction::SKIP(data))
-                } else {
-                    //ensure that st is a key type
-                    if (st != data::SkipType::KeyUp) &&
-                        (st != data::SkipType::KeyDown) {
-                        return Err(AsmError::Skip(
-                                    SkipError::new(st,
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
-                    }
+    /// Gets the warnings found while assembling
+    ///
+    /// Warnings do not stop assembly, but point out code
+    /// that may not do what was intended
+    ///
+    /// # Returns
+    ///
+    /// Every warning found so far, in the order they were found
+    pub fn get_warnings(&self) -> &[AsmWarning] {
+        return &self.warnings;
+    }
 
-                    let data = data::SkipData::with_key(vx, st);
+    /// Gets the address table for the labels in the source code
+    ///
+    /// # Returns
+    ///
+    /// The table mapping each label to its address
+    pub fn get_addrs(&self) -> &AddrTable {
+        return &self.addrs;
+    }
 
-                    Ok(Instruction::SKIP(data))
-                }
-            },
-            "MOV" => {
-                //get the destination register
-                let vx = self.register()?;
+    /// Gets the code and data sections marked in the source code
+    /// with `.code` and `.data`
+    ///
+    /// # Returns
+    ///
+    /// The map telling which address ranges are code and which are data
+    pub fn get_sections(&self) -> &SectionMap {
+        return &self.sections;
+    }
 
-                //parse the comma
-                self.eat(&TokenType::Comma)?;
+    /// Gets the listing of the assembled code
+    ///
+    /// # Returns
+    ///
+    /// The listing of every statement assembled so far
+    pub fn get_listing(&self) -> &Listing {
+        return &self.listing;
+    }
 
-                //save the token type
-                let ttype = self.cur_token.get_type().clone();
+    /// Checks the flow of the assembled program for `RET`s that
+    /// have no matching `CALL` and for `CALL`s to addresses that
+    /// are not instructions, adding a warning for each one found
+    ///
+    /// The check follows jumps, calls, and skips from the start of
+    /// the program. Computed jumps (`JPC`) cannot be followed, so
+    /// problems are only ever reported as warnings.
+    pub fn check_calls(&mut self) {
+        //map each instruction to where it was written
+        let instrs: HashMap<u16, (u32, u32)> = self.instrs.iter()
+                                .map(|&(a, l, c)| (a, (l, c)))
+                                .collect();
+
+        //walk the program from its start, tracking whether
+        //each instruction is reached from inside a subroutine
+        let mut found = Vec::new();
+        let mut seen = HashSet::new();
+        let mut todo = vec![(self.origin, false)];
+        while let Some((addr, in_sub)) = todo.pop() {
+            //only visit instructions, and each one once per state
+            let pos = match instrs.get(&addr) {
+                Some(p) => *p,
+                None => continue
+            };
+            if !seen.insert((addr, in_sub)) {
+                continue;
+            }
 
-                //handle source objects
-                if ttype == TokenType::Register {
-                    //ensure that the VX register is not the I register
-                    if vx == data::Register::I {
-                        return Err(AsmError::Argument(
-                                    ArgError::new(&vx, "MOV",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+            //decode the instruction
+            let idx = usize::from(addr - self.origin);
+            let instr = decode_opcode(self.bytes[idx], self.bytes[idx + 1]);
+            let next = addr.wrapping_add(self.instr_len(addr));
+            let after = next.wrapping_add(self.instr_len(next));
+
+            //and follow it to the instructions it can reach
+            match instr {
+                Some(Instr::Ret) => {
+                    if !in_sub {
+                        found.push((pos,
+                            String::from("RET with no matching CALL")));
                     }
+                },
+                Some(Instr::Exit) | Some(Instr::Jpc(_)) => {},
+                Some(Instr::Jmp(target)) => todo.push((target, in_sub)),
+                Some(Instr::Call(target)) => {
+                    if instrs.contains_key(&target) {
+                        todo.push((target, true));
+                    } else {
+                        found.push((pos, format!(
+                            "CALL to {}{:03X}, which is not an instruction",
+                            constants::HEX_LIT_CHAR, target)));
+                    }
+                    todo.push((next, in_sub));
+                },
+                Some(i) if i.is_skip() => {
+                    todo.push((next, in_sub));
+                    todo.push((after, in_sub));
+                },
+                _ => todo.push((next, in_sub))
+            };
+        }
 
-                    //get the source register
+        //report each problem once, in source order
+        found.sort();
+        found.dedup();
+        for ((line, col), msg) in found {
+            self.warn(&msg, Position::new(line, col));
+        }
+    }
+
+    /// Checks that the assembled program starts with an instruction,
+    /// adding a warning if its first byte is data instead
+    ///
+    /// Data at the start of a program is run as code when the
+    /// program is loaded, which usually means that a jump over the
+    /// data to the entry point is missing. Programs that really do
+    /// start with data can simply skip this check.
+    pub fn check_entry(&mut self) {
+        //empty programs have nothing to run
+        if self.bytes.is_empty() {
+            return;
+        }
+
+        //and warn if the first instruction is not at the start
+        let starts_with_code = match self.instrs.first() {
+            Some(&(addr, _, _)) => addr == self.origin,
+            None => false
+        };
+        if !starts_with_code {
+            let (line, col) = self.entry_pos;
+            self.warn("program starts with data instead of an \
+                       instruction (is a JMP to the entry point missing?)",
+                      Position::new(line, col));
+        }
+    }
+
+    /// Gets the position of the most recently eaten `Token`
+    ///
+    /// # Returns
+    ///
+    /// The line and column the most recently eaten `Token` started at
+    fn last_pos(&self) -> Position {
+        return Position::new(self.last_line, self.last_col);
+    }
+
+    /// Gets the position of the current `Token`
+    ///
+    /// # Returns
+    ///
+    /// The line and column the current `Token` starts at
+    fn cur_pos(&self) -> Position {
+        return Position::new(self.tok_line, self.tok_col);
+    }
+
+    /// Writes a 16-bit word to the assembled bytes
+    ///
+    /// # Argument
+    ///
+    /// * `word` - The word to write
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the word was written, `Err(AsmError)` otherwise
+    fn emit_word(&mut self, word: u16) -> Result<(), AsmError> {
+        self.reserve(2)?;
+        self.bytes.extend_from_slice(&word.to_be_bytes());
+        return Ok(());
+    }
+
+    /// Writes a byte to the assembled bytes
+    ///
+    /// # Argument
+    ///
+    /// * `byte` - The byte to write
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the byte was written, `Err(AsmError)` otherwise
+    fn emit_byte(&mut self, byte: u8) -> Result<(), AsmError> {
+        self.reserve(1)?;
+        self.bytes.push(byte);
+        return Ok(());
+    }
+
+    /// Ensures that bytes written at the current address
+    /// fit in memory
+    ///
+    /// # Argument
+    ///
+    /// * `count` - The number of bytes about to be written
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the bytes end at or before the last address of
+    /// memory, `Err(OverflowError)` otherwise
+    fn reserve(&self, count: usize) -> Result<(), AsmError> {
+        let end = (self.origin as usize) + self.bytes.len() + count;
+        if end > (constants::MEM_SIZE as usize) {
+            let over = end - (constants::MEM_SIZE as usize);
+            return Err(AsmError::Overflow(OverflowError::new(over)));
+        }
+
+        return Ok(());
+    }
+
+    /// Skips to the start of the next statement after an error
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The position the failed statement started at
+    /// * `errs` - The list to add lexer errors found while skipping to
+    fn sync(&mut self, start: Position, errs: &mut Vec<AsmError>) {
+        //a statement that failed after reading its last token
+        //has already left the lexer on the next line
+        //(or at a separator, which starts the next statement)
+        if (self.cur_pos() != start) &&
+            ((self.tok_line > self.last_line) ||
+             (self.cur_token.get_type() == TokenType::Separator)) {
+            return;
+        }
+
+        //get the line that the error occurred on
+        let err_line = self.tok_line;
+
+        //and skip tokens until a new line, separator,
+        //or label definition
+        loop {
+            match self.lexer.get_next_token() {
+                Ok(t) => {
+                    self.cur_token = t;
+                    self.tok_line = self.lexer.get_token_line();
+                    self.tok_col = self.lexer.get_token_column();
+                },
+                Err(le) => {
+                    errs.push(Assembler::annotate(&self.lexer, le.into()));
+                    continue;
+                }
+            };
+
+            if (self.cur_token.get_type() == TokenType::EndOfInput) ||
+                (self.cur_token.get_type() == TokenType::LblDef) ||
+                (self.cur_token.get_type() == TokenType::Separator) ||
+                (self.tok_line > err_line) {
+                break;
+            }
+        }
+    }
+
+    /// Ensures that the assembled program fits in memory
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the program ends at or before the last address of
+    /// memory, `Err(OverflowError)` otherwise
+    fn check_size(&self) -> Result<(), AsmError> {
+        //get the written length (binaries are padded to whole words)
+        let len = self.bytes.len() + (self.bytes.len() % 2);
+
+        //and compare the end of the program to the end of memory
+        let end = (self.origin as usize) + len;
+        if end > (constants::MEM_SIZE as usize) {
+            let over = end - (constants::MEM_SIZE as usize);
+            return Err(AsmError::Overflow(OverflowError::new(over)));
+        }
+
+        return Ok(());
+    }
+
+    /// Attaches the offending line of source code to an error
+    ///
+    /// # Arguments
+    ///
+    /// * `lexer` - The lexer holding the source code
+    /// * `err` - The error to annotate
+    ///
+    /// # Returns
+    ///
+    /// The error with its source line attached
+    fn annotate(lexer: &AsmLexer, mut err: AsmError) -> AsmError {
+        if let Some(line) = err.get_line() {
+            err.set_source(&lexer.get_line_text(line));
+        }
+        return err;
+    }
+
+    /// Verifies the current token and gets the next token
+    /// 
+    /// # Argument
+    ///
+    /// * `ttype` - The token type to verify against
+    ///
+    /// # Returns
+    ///
+    /// The verified token if no errors occur, `Err(ParseError)` if
+    /// the token is not verified properly, or `Err(LexerError)` if
+    /// an unknown character is found
+    fn eat(&mut self, ttype: &TokenType ) -> Result<Token, AsmError> {
+        //check the type
+        if self.cur_token.get_type() == *ttype {
+            let t = self.lexer.get_next_token()?;
+            self.last_line = self.tok_line;
+            self.last_col = self.tok_col;
+            self.tok_line = self.lexer.get_token_line();
+            self.tok_col = self.lexer.get_token_column();
+            return Ok(mem::replace(&mut self.cur_token, t));
+        } else {
+            return Err(AsmError::Parser(ParseError::new(
+                        ttype, &self.cur_token.get_type(),
+                        Position::new(self.tok_line, self.tok_col))));
+        }
+    }
+
+    /// Assembles an instruction
+    /// 
+    /// # Returns
+    ///
+    /// The assembled instruction, wrapped in a `Result`
+    fn instruction(&mut self) -> Result<Instr, AsmError> {
+        //parse the instruction, keeping its token and position
+        let pos = self.cur_pos();
+        let save_token = self.eat(&TokenType::Instruction)?;
+
+        //get the instruction string
+        let instr = save_token.get_value_ref().as_text_ref().unwrap();
+
+        //and generate the instruction
+        return match instr {
+            "CLS" => Ok(Instr::Cls),
+            "RET" => Ok(Instr::Ret),
+            "SCR" => Ok(Instr::Scr),
+            "SCL" => Ok(Instr::Scl),
+            "EXIT" => Ok(Instr::Exit),
+            "LOW" => Ok(Instr::Low),
+            "HIGH" => Ok(Instr::High),
+            "MOVL" => {
+                //get the destination register
+                let vx = self.register()?;
+
+                //ensure that it is the I register
+                if vx != data::Register::I {
+                    return Err(AsmError::Argument(
+                                ArgError::new(&vx, "MOVL",
+                                        self.last_pos())));
+                }
+
+                //parse the comma
+                self.eat(&TokenType::Comma)?;
+
+                //get the full 16-bit address, which is
+                //written in the word after the opcode
+                let addr = self.field(16)?;
+
+                //and return the instruction
+                Ok(Instr::Movl(addr))
+            },
+            "JMP" => {
+                //determine which type of argument to use
+                if self.cur_token.get_type() == TokenType::Label {
+                    let addr = self.label()?;
+                    //and return the instruction
+                    Ok(Instr::Jmp(addr & 0x0FFF))
+                } else {
+                    let addr = self.field(12)?;
+                    //and return the instruction
+                    Ok(Instr::Jmp(addr))
+                }
+            },
+            "CALL" => {
+                //determine which type of argument to use
+                if self.cur_token.get_type() == TokenType::Label {
+                    let addr = self.label()?;
+                    //and return the instruction
+                    Ok(Instr::Call(addr & 0x0FFF))
+                } else {
+                    let addr = self.field(12)?;
+                    //and return the instruction
+                    Ok(Instr::Call(addr))
+                }
+            },
+            "SKIP" => {
+                //parse the period
+                self.eat(&TokenType::Period)?;
+
+                //get the skip type
+                let st = self.skiptype()?;
+
+                //get the first argument
+                let vx = self.register()?;
+
+                //ensure that VX is not the I register
+                if vx == data::Register::I {
+                    return Err(AsmError::Argument(
+                                ArgError::new(&vx, "SKIP",
+                                        self.last_pos())));
+                }
+
+                //parse a possible comma
+                let paired = (st == data::SkipType::Equals) ||
+                                (st == data::SkipType::NotEquals);
+                if paired {
+                    self.eat(&TokenType::Comma)?;
+                }
+
+                //save the tokentype
+                let ttype = self.cur_token.get_type().clone();
+
+                //and generate the instruction
+                //(key skips take no second argument, so a symbol
+                //or a constant on a later line after one starts
+                //the next statement)
+                let same_line = self.tok_line == self.last_line;
+                if (ttype == TokenType::Register) ||
+                    (paired && self.at_register()) {
+                    //parse the register
+                    let vy = self.register()?;
+
+                    //ensure that VY is not the I register
+                    if vy == data::Register::I {
+                        return Err(AsmError::Argument(
+                                    ArgError::new(&vy, "SKIP",
+                                            self.last_pos())));
+                    }
+
+                    //ensure that the skip type is not a key variant
+                    if (st == data::SkipType::KeyUp) ||
+                        (st == data::SkipType::KeyDown) {
+                        return Err(AsmError::Skip(
+                                    SkipError::new(st,
+                                            self.last_pos())));
+                    }
+
+                    //and return the instruction
+                    if st == data::SkipType::Equals {
+                        Ok(Instr::SkipEqReg(reg_id(&vx), reg_id(&vy)))
+                    } else {
+                        Ok(Instr::SkipNeReg(reg_id(&vx), reg_id(&vy)))
+                    }
+                } else if self.at_constant() && (paired || same_line) {
+                    //get the value
+                    let nn = self.truncated(8)?;
+
+                    //ensure that the skip type is not a key variant
+                    if (st == data::SkipType::KeyUp) ||
+                        (st == data::SkipType::KeyDown) {
+                        return Err(AsmError::Skip(
+                                    SkipError::new(st,
+                                            self.last_pos())));
+                    }
+
+                    //and return the instruction
+                    if st == data::SkipType::Equals {
+                        Ok(Instr::SkipEqConst(reg_id(&vx), nn as u8))
+                    } else {
+                        Ok(Instr::SkipNeConst(reg_id(&vx), nn as u8))
+                    }
+                } else {
+                    //ensure that st is a key type
+                    if (st != data::SkipType::KeyUp) &&
+                        (st != data::SkipType::KeyDown) {
+                        return Err(AsmError::Skip(
+                                    SkipError::new(st,
+                                            self.last_pos())));
+                    }
+
+                    //and return the instruction
+                    if st == data::SkipType::KeyDown {
+                        Ok(Instr::SkipKeyDown(reg_id(&vx)))
+                    } else {
+                        Ok(Instr::SkipKeyUp(reg_id(&vx)))
+                    }
+                }
+            },
+            "MOV" => {
+                //handle writing a timer
+                if self.cur_token.get_type() == TokenType::Timer {
+                    return self.timer_write();
+                }
+
+                //get the destination register
+                let vx = self.register()?;
+
+                //parse the comma
+                self.eat(&TokenType::Comma)?;
+
+                //save the token type
+                let ttype = self.cur_token.get_type().clone();
+
+                //handle source objects
+                if ttype == TokenType::Timer {
+                    //ensure that the VX register is not the I register
+                    if vx == data::Register::I {
+                        return Err(AsmError::Argument(
+                                    ArgError::new(&vx, "MOV",
+                                            self.last_pos())));
+                    }
+
+                    //only the delay timer can be read
+                    let timer_pos = self.cur_pos();
+                    let timer = self.eat(&TokenType::Timer)?;
+                    if timer.get_value_ref().as_text().unwrap() != "DELAY" {
+                        return Err(AsmError::Parser(ParseError::new(
+                                    &TokenType::Register, &TokenType::Timer,
+                                    timer_pos)));
+                    }
+
+                    //and return the instruction (the same as GDL)
+                    Ok(Instr::Gdl(reg_id(&vx)))
+                } else if self.at_register() {
+                    //ensure that the VX register is not the I register
+                    if vx == data::Register::I {
+                        return Err(AsmError::Argument(
+                                    ArgError::new(&vx, "MOV",
+                                            self.last_pos())));
+                    }
+
+                    //get the source register
                     let vy = self.register()?;
 
                     //ensure that the VY register is not the I register
                     if vy == data::Register::I {
                         return Err(AsmError::Argument(
                                     ArgError::new(&vy, "MOV",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                     }
 
-                    //construct the data
-                    let data = data::MovData::with_register(vx, vy);
+                    //note a register being moved to itself
+                    if vx == vy {
+                        self.warn("moving a register to itself has no effect",
+                                  pos);
+                    }
 
                     //and return the instruction
-                    Ok(Instruction::MOV(data))
+                    Ok(Instr::MovReg(reg_id(&vx), reg_id(&vy)))
                 } else if ttype == TokenType::Label {
                     //ensure that vx is the `I` register
                     if vx != data::Register::I {
                         return Err(AsmError::Argument(
                                     ArgError::new(&vx, "MOV",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                     }
 
                     //parse the label
                     let addr = self.label()?;
 
-                    //assemble the data
-                    let data = data::MovData::with_constant(vx, addr);
+                    //and return the instruction
+                    Ok(Instr::MovI(addr & 0x0FFF))
+                } else if vx == data::Register::I {
+                    //get the literal address (the I register holds 12 bits)
+                    let addr = self.field(12)?;
 
                     //and return the instruction
-                    Ok(Instruction::MOV(data))
+                    Ok(Instr::MovI(addr))
                 } else {
-                    //get the constant
-                    let cst = self.constant()?;
-
-                    //construct the data 
-                    let data = data::MovData::with_constant(vx, cst);
+                    //get the constant (negative literals
+                    //are packed into a byte)
+                    let val_pos = self.cur_pos();
+                    let val = self.constant()?;
+
+                    //ensure that it fits in the register
+                    if val > constants::MAX_BYTE_LIT {
+                        return Err(AsmError::Range(RangeError::new(val, 8,
+                                                            val_pos)));
+                    }
 
                     //and return the instruction
-                    Ok(Instruction::MOV(data))
+                    Ok(Instr::MovConst(reg_id(&vx), val as u8))
                 } 
             },
             "ADD" => {
@@ -409,7 +1205,7 @@ impl Assembler {
                 self.eat(&TokenType::Comma)?;
 
                 //determine the second argument type
-                if self.cur_token.get_type() == TokenType::Register {
+                if self.at_register() {
                     //get the second register
                     let vy = self.register()?;
 
@@ -417,32 +1213,33 @@ impl Assembler {
                     if vy == data::Register::I {
                         return Err(AsmError::Argument(
                                         ArgError::new(&vy, "ADD",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                     }
 
-                    //construct the data
-                    let data = data::AddData::with_register(vx, vy);
-
-                    //and return the instruction
-                    return Ok(Instruction::ADD(data));
+                    //and return the instruction (adding to I uses Fx1E)
+                    if vx == data::Register::I {
+                        Ok(Instr::AddI(reg_id(&vy)))
+                    } else {
+                        Ok(Instr::AddReg(reg_id(&vx), reg_id(&vy)))
+                    }
                 } else {
                     //get the constant
-                    let cst = self.constant()?;
+                    let cst = self.truncated(8)?;
 
                     //ensure that VX is not the I register
                     if vx == data::Register::I {
                         return Err(AsmError::Argument(
                                     ArgError::new(&vx, "ADD",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                     }
 
-                    //construct the data
-                    let data = data::AddData::with_constant(vx, cst);
+                    //note adding zero
+                    if cst == 0 {
+                        self.warn("adding zero has no effect", pos);
+                    }
 
                     //and return the instruction
-                    Ok(Instruction::ADD(data))
+                    Ok(Instr::AddConst(reg_id(&vx), cst as u8))
                 }
             },
             "OR" => {
@@ -453,8 +1250,7 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "OR",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
                 //parse the comma
@@ -467,15 +1263,11 @@ impl Assembler {
                 if vy == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vy, "OR",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::OrData::new(vx, vy);
-
                 //and return the instruction
-                Ok(Instruction::OR(data))
+                Ok(Instr::Or(reg_id(&vx), reg_id(&vy)))
             },
             "AND" => {
                 //get the first argument register
@@ -485,8 +1277,7 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "AND",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
                 //parse the comma
@@ -499,15 +1290,11 @@ impl Assembler {
                 if vy == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vy, "AND",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::AndData::new(vx, vy);
-
                 //and return the instruction
-                Ok(Instruction::AND(data))
+                Ok(Instr::And(reg_id(&vx), reg_id(&vy)))
             },
             "XOR" => {
                 //get the first argument register
@@ -517,8 +1304,7 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "XOR",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
                 //parse the comma
@@ -531,15 +1317,11 @@ impl Assembler {
                 if vy == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vy, "XOR",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::XorData::new(vx, vy);
-
                 //and return the instruction
-                Ok(Instruction::XOR(data))
+                Ok(Instr::Xor(reg_id(&vx), reg_id(&vy)))
             },
             "SUB" => {
                 //get the first argument register
@@ -549,8 +1331,7 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "SUB",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
                 //parse the comma
@@ -563,15 +1344,11 @@ impl Assembler {
                 if vy == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vy, "SUB",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::SubData::new(vx, vy);
-
                 //and return the instruction
-                Ok(Instruction::SUB(data))
+                Ok(Instr::Sub(reg_id(&vx), reg_id(&vy)))
             },
             "SHR" => {
                 //get the argument register
@@ -581,15 +1358,11 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "SHR",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::ShrData::new(vx);
-
                 //and return the instruction
-                Ok(Instruction::SHR(data))
+                Ok(Instr::Shr(reg_id(&vx)))
             },
             "SUBN" => {
                 //get the first argument register
@@ -599,8 +1372,7 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "SUBN",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
                 //parse the comma
@@ -613,15 +1385,11 @@ impl Assembler {
                 if vy == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vy, "SUBN",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::SubnData::new(vx, vy);
-
                 //and return the instruction
-                Ok(Instruction::SUBN(data))
+                Ok(Instr::Subn(reg_id(&vx), reg_id(&vy)))
             },
            "SHL" => {
                 //get the argument register
@@ -631,26 +1399,22 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "SHL",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::ShlData::new(vx);
-
                 //and return the instruction
-                Ok(Instruction::SHL(data))
+                Ok(Instr::Shl(reg_id(&vx)))
             },
             "JPC" => {
                 //determine which type of argument to use
                 if self.cur_token.get_type() == TokenType::Label {
                     let addr = self.label()?;
-                    let data = data::JpcData::new(addr);
-                    Ok(Instruction::JPC(data))
+                    //and return the instruction
+                    Ok(Instr::Jpc(addr & 0x0FFF))
                 } else {
-                    let addr = self.constant()?;
-                    let data = data::JpcData::new(addr);
-                    Ok(Instruction::JPC(data))
+                    let addr = self.field(12)?;
+                    //and return the instruction
+                    Ok(Instr::Jpc(addr))
                 }
             },
             "RAND" => {
@@ -661,22 +1425,17 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "RAND",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
                 //parse the comma
                 self.eat(&TokenType::Comma)?;
 
                 //get the limiting value
-                let nn16 = self.constant()?;
-                let nn = (nn16 & 0xFF) as u8;
-
-                //construct the data
-                let data = data::RandData::new(vx, nn);
+                let nn = self.field(8)?;
 
                 //and return the instruction
-                Ok(Instruction::RAND(data))
+                Ok(Instr::Rand(reg_id(&vx), nn as u8))
             },
             "DRAW" => {
                 //get the X register
@@ -686,8 +1445,7 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "DRAW",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
                 //parse the comma
@@ -700,22 +1458,17 @@ impl Assembler {
                 if vy == data::Register::I {
                     return Err(AsmError::Argument(
                                         ArgError::new(&vy, "DRAW",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
                 //parse the comma
                 self.eat(&TokenType::Comma)?;
 
                 //get the height
-                let h16 = self.constant()?;
-                let h = (h16 & 0xF) as u8;
-
-                //construct the data
-                let data = data::DrawData::new(vx, vy, h);
+                let h = self.field(4)?;
 
                 //and return the instruction
-                Ok(Instruction::DRAW(data))
+                Ok(Instr::Draw(reg_id(&vx), reg_id(&vy), h as u8))
             },
            "GDL" => {
                 //get the argument register
@@ -725,15 +1478,11 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "GDL",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::GdlData::new(vx);
-
                 //and return the instruction
-                Ok(Instruction::GDL(data))
+                Ok(Instr::Gdl(reg_id(&vx)))
             },
            "KEY" => {
                 //get the argument register
@@ -743,15 +1492,11 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "KEY",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::KeyData::new(vx);
-
                 //and return the instruction
-                Ok(Instruction::KEY(data))
+                Ok(Instr::Key(reg_id(&vx)))
             },
            "SDL" => {
                 //get the argument register
@@ -761,15 +1506,11 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "SDL",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::SdlData::new(vx);
-
                 //and return the instruction
-                Ok(Instruction::SDL(data))
+                Ok(Instr::Sdl(reg_id(&vx)))
             },
            "SND" => {
                 //get the argument register
@@ -779,69 +1520,67 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "SND",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::SndData::new(vx);
-
                 //and return the instruction
-                Ok(Instruction::SND(data))
+                Ok(Instr::Snd(reg_id(&vx)))
             },
-           "SCH" => {
+           "SCH" | "FONT" => {
                 //get the argument register
                 let vx = self.register()?;
 
                 //ensure that it does not refer to the I register
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
-                                    ArgError::new(&vx, "SCH",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                    ArgError::new(&vx, instr,
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::SchData::new(vx);
-
                 //and return the instruction
-                Ok(Instruction::SCH(data))
+                Ok(Instr::Sch(reg_id(&vx)))
             },
-           "BCD" => {
+           "LCH" => {
                 //get the argument register
                 let vx = self.register()?;
 
                 //ensure that it does not refer to the I register
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
-                                    ArgError::new(&vx, "BCD",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                    ArgError::new(&vx, "LCH",
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::BcdData::new(vx);
-
                 //and return the instruction
-                Ok(Instruction::BCD(data))
+                Ok(Instr::Lch(reg_id(&vx)))
             },
-            "RDP" => {
+           "BCD" => {
                 //get the argument register
                 let vx = self.register()?;
 
                 //ensure that it does not refer to the I register
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
-                                    ArgError::new(&vx, "RDP",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                    ArgError::new(&vx, "BCD",
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::RdpData::new(vx);
+                //and return the instruction
+                Ok(Instr::Bcd(reg_id(&vx)))
+            },
+            "RDP" => {
+                //get the argument register
+                let vx = self.register()?;
+
+                //ensure that it does not refer to the I register
+                if vx == data::Register::I {
+                    return Err(AsmError::Argument(
+                                    ArgError::new(&vx, "RDP",
+                                            self.last_pos())));
+                }
 
                 //and return the instruction
-                Ok(Instruction::RDP(data))
+                Ok(Instr::Rdp(reg_id(&vx)))
             },
            "RLD" => {
                 //get the argument register
@@ -851,38 +1590,411 @@ impl Assembler {
                 if vx == data::Register::I {
                     return Err(AsmError::Argument(
                                     ArgError::new(&vx, "RLD",
-                                            self.lexer.get_line(),
-                                            self.lexer.get_column())));
+                                            self.last_pos())));
                 }
 
-                //construct the data
-                let data = data::RldData::new(vx);
-
                 //and return the instruction
-                Ok(Instruction::RLD(data))
+                Ok(Instr::Rld(reg_id(&vx)))
             },
-            _ => Err(AsmError::Opcode(OpcodeError::new(instr.as_str(),
-                        self.lexer.get_line(),
-                        self.lexer.get_column())))
+            _ => Err(AsmError::Opcode(OpcodeError::new(instr,
+                        self.last_pos())))
         };
     }
 
-    /// Assembles a constant literal
+    /// Determines whether the current token is a directive
+    ///
+    /// # Returns
+    ///
+    /// Whether the current token names an assembler directive
+    fn is_directive(&self) -> bool {
+        if self.cur_token.get_type() != TokenType::Instruction {
+            return false;
+        }
+        let name = self.cur_token.get_value_ref().as_text_ref().unwrap();
+        return constants::DIRECTIVES.contains(&name);
+    }
+
+    /// Assembles a directive
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the directive was assembled, `Err(AsmError)` otherwise
+    fn directive(&mut self) -> Result<(), AsmError> {
+        //parse the directive, keeping its token
+        let save_token = self.eat(&TokenType::Instruction)?;
+
+        //get the directive string
+        let name = save_token.get_value_ref().as_text_ref().unwrap();
+
+        //and assemble the directive
+        if name == "DS" {
+            //get the number of bytes to reserve
+            let count = self.constant()?;
+
+            //and write that many zero bytes
+            self.reserve(count as usize)?;
+            for _ in 0..count {
+                self.emit_byte(0x00)?;
+            }
+        } else if name == "DB" {
+            //write the first value
+            self.byte_value()?;
+
+            //and write the rest of the list
+            while self.cur_token.get_type() == TokenType::Comma {
+                self.eat(&TokenType::Comma)?;
+                self.byte_value()?;
+            }
+        } else if name == "DW" {
+            //write the first word
+            self.word_value()?;
+
+            //and write the rest of the list
+            while self.cur_token.get_type() == TokenType::Comma {
+                self.eat(&TokenType::Comma)?;
+                self.word_value()?;
+            }
+        } else if name == constants::FONT_DIRECTIVE {
+            //label the font if it was not labeled while preprocessing
+            if !self.addrs.has_entry(constants::FONT_LABEL) {
+                let addr = self.get_address();
+                self.addrs.add_entry(constants::FONT_LABEL, addr);
+            }
+
+            //and write the sprites
+            for b in constants::FONT_SPRITES.iter() {
+                self.emit_byte(*b)?;
+            }
+        } else if name == "ALIGN" {
+            //get the boundary to align to
+            let bound = if self.at_constant() {
+                self.constant()?
+            } else {
+                constants::DEFAULT_ALIGN
+            };
+
+            //and pad with zero bytes until the address is aligned
+            if bound != 0 {
+                let addr = self.get_address() as usize;
+                let pad = (bound as usize - (addr % bound as usize)) %
+                            (bound as usize);
+                for _ in 0..pad {
+                    self.emit_byte(0x00)?;
+                }
+            }
+        }
+
+        //and return a success
+        return Ok(());
+    }
+
+    /// Assembles a value in a `DB` list, which is either a byte
+    /// or a string literal written one byte per character
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the value was written, `Err(AsmError)` otherwise
+    fn byte_value(&mut self) -> Result<(), AsmError> {
+        if self.cur_token.get_type() == TokenType::StrLit {
+            let save_token = self.eat(&TokenType::StrLit)?;
+            let text = save_token.get_value_ref().as_text_ref().unwrap();
+            for b in text.bytes() {
+                self.emit_byte(b)?;
+            }
+        } else {
+            let b = self.truncated(8)?;
+            self.emit_byte(b as u8)?;
+        }
+        return Ok(());
+    }
+
+    /// Assembles a value in a `DW` list, writing
+    /// negatives as their 16-bit two's complement
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the value was written, `Err(AsmError)` otherwise
+    fn word_value(&mut self) -> Result<(), AsmError> {
+        let (w, _) = self.expr()?;
+        self.emit_word(w as u16)?;
+        return Ok(());
+    }
+
+    /// Determines whether the current `Token` starts a constant
+    /// (a literal or a parenthesized expression)
+    ///
+    /// # Returns
+    ///
+    /// Whether the current `Token` starts a constant
+    fn at_constant(&self) -> bool {
+        let ttype = self.cur_token.get_type_ref();
+        return (*ttype == TokenType::DecLit) ||
+                (*ttype == TokenType::NegLit) ||
+                (*ttype == TokenType::HexLit) ||
+                (*ttype == TokenType::BinLit) ||
+                (*ttype == TokenType::LeftParen);
+    }
+
+    /// Assembles a constant, which may be an arithmetic expression.
+    /// Negative constants are packed into their two's-complement byte.
     ///
     /// # Returns
     ///
-    /// The constant literal, wrapped in a `Result`
+    /// The value of the constant, or an `AsmError` if it is
+    /// too negative to fit in a byte
     fn constant(&mut self) -> Result<u16, AsmError> {
+        //evaluate the expression
+        let pos = self.cur_pos();
+        let (val, _) = self.expr()?;
+
+        //ensure that a negative value fits in a signed byte
+        if val < constants::MIN_BYTE_LIT {
+            return Err(AsmError::Range(RangeError::new(val as u16, 8,
+                                                       pos)));
+        }
+
+        //and pack it
+        if val < 0 {
+            return Ok((val as u16) & constants::MAX_BYTE_LIT);
+        }
+        return Ok(val as u16);
+    }
+
+    /// Assembles a constant that must fit in a field of an instruction
+    ///
+    /// # Argument
+    ///
+    /// * `bits` - The width of the field in bits
+    ///
+    /// # Returns
+    ///
+    /// The value of the constant, or an `AsmError` if it
+    /// does not fit in the field
+    fn field(&mut self, bits: u32) -> Result<u16, AsmError> {
+        //evaluate the constant
+        let pos = self.cur_pos();
+        let val = self.constant()?;
+
+        //and ensure that it fits in the field
+        if u32::from(val) >= (1 << bits) {
+            return Err(AsmError::Range(RangeError::new(val, bits, pos)));
+        }
+        return Ok(val);
+    }
+
+    /// Assembles a constant that is packed into a field of a given
+    /// width, warning if any of its bits do not fit
+    ///
+    /// # Argument
+    ///
+    /// * `bits` - The width of the field in bits
+    ///
+    /// # Returns
+    ///
+    /// The value of the constant cut down to the field,
+    /// wrapped in a `Result`
+    fn truncated(&mut self, bits: u32) -> Result<u16, AsmError> {
+        //evaluate the constant
+        let pos = self.cur_pos();
+        let val = self.constant()?;
+
+        //and pack it into the field
+        return Ok(self.truncate(val, bits, pos));
+    }
+
+    /// Packs a value into a field of a given width,
+    /// warning if any of its bits do not fit
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to pack
+    /// * `bits` - The width of the field in bits
+    /// * `pos` - The position the value was found at
+    ///
+    /// # Returns
+    ///
+    /// The bits of the value that fit in the field
+    fn truncate(&mut self, val: u16, bits: u32, pos: Position) -> u16 {
+        //warn if the value does not fit in the field
+        let mask = ((1u32 << bits) - 1) as u16;
+        if (val & !mask) != 0 {
+            let msg = format!("value {}{:X} truncated to {} bits",
+                              constants::HEX_LIT_CHAR, val, bits);
+            self.warn(&msg, pos);
+        }
+        return val & mask;
+    }
+
+    /// Gets the length of the instruction at an address
+    ///
+    /// # Argument
+    ///
+    /// * `addr` - The address of the instruction
+    ///
+    /// # Returns
+    ///
+    /// 4 for a `MOVL` (an `F000` opcode followed by an address),
+    /// otherwise 2
+    fn instr_len(&self, addr: u16) -> u16 {
+        let idx = match addr.checked_sub(self.origin) {
+            Some(off) => usize::from(off),
+            None => return 2
+        };
+        if self.bytes.get(idx..idx + 2) == Some(&[0xF0, 0x00][..]) {
+            return 4;
+        } else {
+            return 2;
+        }
+    }
+
+    /// Records a warning, along with the line it was found on
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - A description of the problem
+    /// * `pos` - The position the warning was found at
+    fn warn(&mut self, msg: &str, pos: Position) {
+        let mut warning = AsmWarning::new(msg, pos);
+        warning.set_source(&self.lexer.get_line_text(pos.get_line()));
+        self.warnings.push(warning);
+    }
+
+    /// Assembles an address, which is a label reference
+    /// optionally followed by arithmetic on it
+    ///
+    /// # Returns
+    ///
+    /// The address, or an `AsmError` if it does
+    /// not fit in an instruction
+    fn label(&mut self) -> Result<u16, AsmError> {
+        //ensure that the address starts with a label
+        if self.cur_token.get_type() != TokenType::Label {
+            self.eat(&TokenType::Label)?;
+        }
+
+        //evaluate it
+        let pos = self.cur_pos();
+        let (addr, _) = self.expr()?;
+
+        //and ensure that it fits in 12 bits
+        if (addr < 0) || (addr > i32::from(constants::MAX_ADDR)) {
+            return Err(AsmError::Range(RangeError::new(addr as u16, 12,
+                                                       pos)));
+        }
+        return Ok(addr as u16);
+    }
+
+    /// Assembles a `MOV` that writes a register to a timer
+    ///
+    /// # Returns
+    ///
+    /// The instruction for setting the timer, wrapped in a `Result`
+    fn timer_write(&mut self) -> Result<Instr, AsmError> {
+        //get the timer
+        let timer = self.eat(&TokenType::Timer)?;
+
+        //parse the comma
+        self.eat(&TokenType::Comma)?;
+
+        //get the source register
+        let vx = self.register()?;
+
+        //ensure that it's not the I register
+        if vx == data::Register::I {
+            return Err(AsmError::Argument(ArgError::new(&vx, "MOV",
+                                                        self.last_pos())));
+        }
+
+        //and return the instruction (the same as SDL or SND)
+        if timer.get_value_ref().as_text().unwrap() == "DELAY" {
+            return Ok(Instr::Sdl(reg_id(&vx)));
+        } else {
+            return Ok(Instr::Snd(reg_id(&vx)));
+        }
+    }
+
+    /// Assembles an arithmetic expression of `+`, `-`, `*`, and `/`
+    /// over literals, label addresses, and parenthesized expressions
+    ///
+    /// # Returns
+    ///
+    /// The value of the expression and whether it must be stored
+    /// as a word, wrapped in a `Result`
+    fn expr(&mut self) -> Result<(i32, bool), AsmError> {
+        //get the first term
+        let mut lhs = self.term()?;
+
+        //and add or subtract the following terms
+        loop {
+            let op = self.cur_token.get_type();
+            if (op != TokenType::Plus) && (op != TokenType::Minus) {
+                break;
+            }
+            let pos = self.cur_pos();
+            self.eat(&op)?;
+            let rhs = self.term()?;
+            lhs = apply_op(&op, lhs, rhs, pos)?;
+        }
+        return Ok(lhs);
+    }
+
+    /// Assembles a term of an arithmetic expression
+    ///
+    /// # Returns
+    ///
+    /// The value of the term and whether it must be stored
+    /// as a word, wrapped in a `Result`
+    fn term(&mut self) -> Result<(i32, bool), AsmError> {
+        //get the first factor
+        let mut lhs = self.factor()?;
+
+        //and multiply or divide by the following factors
+        loop {
+            let op = self.cur_token.get_type();
+            if (op != TokenType::Asterisk) && (op != TokenType::Slash) {
+                break;
+            }
+            let pos = self.cur_pos();
+            self.eat(&op)?;
+            let rhs = self.factor()?;
+            lhs = apply_op(&op, lhs, rhs, pos)?;
+        }
+        return Ok(lhs);
+    }
+
+    /// Assembles a factor of an arithmetic expression
+    ///
+    /// # Returns
+    ///
+    /// The value of the factor and whether it must be stored
+    /// as a word, wrapped in a `Result`
+    fn factor(&mut self) -> Result<(i32, bool), AsmError> {
         //handle different types
-        if self.cur_token.get_type() == TokenType::DecLit {
-            let dec = self.dec_lit()?;
-            return Ok(dec);
-        } else if self.cur_token.get_type() == TokenType::HexLit {
-            let hex = self.hex_lit()?;
-            return Ok(hex);
-        } else { //binary literal expected
+        let ttype = self.cur_token.get_type();
+        if ttype == TokenType::LeftParen {
+            self.eat(&TokenType::LeftParen)?;
+            let ret = self.expr()?;
+            self.eat(&TokenType::RightParen)?;
+            return Ok(ret);
+        } else if ttype == TokenType::Label {
+            //label addresses are always words
+            return Ok((i32::from(self.label_ref()?), true));
+        } else if ttype == TokenType::DecLit {
+            return Ok((i32::from(self.dec_lit()?), false));
+        } else if ttype == TokenType::NegLit {
+            return Ok((self.neg_lit()?, false));
+        } else if ttype == TokenType::HexLit {
+            return Ok((i32::from(self.hex_lit()?), false));
+        } else if ttype == TokenType::BinLit {
             let bin = self.bin_lit()?;
-            return Ok(bin as u16);
+            return match bin {
+                Variant::Word(w) => Ok((i32::from(w), true)),
+                _ => Ok((i32::from(bin.as_byte().unwrap()), false))
+            };
+        } else { //nothing else can start a value
+            let reason = format!("expected a value but found {}", ttype);
+            return Err(AsmError::Expression(ExprError::new(&reason,
+                                                        self.cur_pos())));
         }
     }
 
@@ -891,47 +2003,76 @@ impl Assembler {
     /// # Returns
     ///
     /// The address of the label, wrapped in a `Result`
-    fn label(&mut self) -> Result<u16, AsmError> {
-        //save the token
-        let save_token = self.cur_token.clone();
-
-        //parse the label
-        self.eat(&TokenType::Label)?;
+    fn label_ref(&mut self) -> Result<u16, AsmError> {
+        //parse the label, keeping its token and position
+        let pos = Position::new(self.tok_line, self.tok_col);
+        let save_token = self.eat(&TokenType::Label)?;
 
         //get the label string
-        let lstr = save_token.get_value().as_text().unwrap();
+        let lstr = save_token.get_value_ref().as_text_ref().unwrap();
 
-        //and get the address
-        return match self.addrs.get_entry(&lstr) {
-            Ok(addr) => Ok(addr),
-            Err(ae) => Err(AsmError::Address(ae))
-        };
+        //and get the address of the label in the current scope
+        let name = AddrTable::scoped_name(&self.scope, lstr);
+        return Ok(self.addrs.get_entry(&name)
+                    .map_err(|ae| ae.with_position(pos))?);
     }
     
 
+    /// Determines whether the current `Token` can be assembled
+    /// as a register (either a register or a register alias)
+    ///
+    /// # Returns
+    ///
+    /// Whether the current `Token` refers to a register
+    fn at_register(&self) -> bool {
+        let ttype = self.cur_token.get_type_ref();
+        return (*ttype == TokenType::Register) ||
+                (*ttype == TokenType::Instruction);
+    }
+
     /// Assembles a register reference
     /// 
+    /// A bare symbol is resolved as a register alias
+    ///
     /// # Returns
     ///
     /// The name of the assembled register, wrapped in a `Result`
     fn register(&mut self) -> Result<data::Register, AsmError> {
-        //save the current token
-        let save_token = self.cur_token.clone();
-
-        //parse the register
-        self.eat(&TokenType::Register)?;
-
-        //get the text from the token
-        let rtext = save_token.get_value().as_text().unwrap();
-
-        //get the first and second chars of that text
-        let fchar = rtext.chars().nth(0).unwrap();
+        //parse the register or alias, keeping its token
+        let is_alias = self.cur_token.get_type() == TokenType::Instruction;
+        let save_token = if is_alias {
+            self.eat(&TokenType::Instruction)?
+        } else {
+            self.eat(&TokenType::Register)?
+        };
+        let text = save_token.get_value_ref().as_text_ref().unwrap();
+
+        //resolve register aliases
+        let rtext = if is_alias {
+            //look up the register of the alias (an undefined name
+            //that looks like a register is most likely a typo)
+            match self.aliases.get_entry(text) {
+                Some(r) => r,
+                None if is_reg_typo(text) => return Err(
+                                    AsmError::Register(RegError::new(
+                                        text, self.last_pos()))),
+                None => return Err(AsmError::Alias(
+                                    AliasError::new(text,
+                                                    self.last_pos())))
+            }
+        } else {
+            text
+        };
 
-        //and generate the register
-        if fchar == 'I' {
+        //and generate the register, ignoring case like the lexers do
+        if rtext.eq_ignore_ascii_case("I") {
             return Ok(data::Register::I);
         } else {
-            let schar = rtext.chars().nth(1).unwrap();
+            let mut chars = rtext.chars().skip(1);
+            let schar = match (chars.next(), chars.next()) {
+                (Some(c), None) => c.to_ascii_uppercase(),
+                _ => '\0'
+            };
             return match schar {
                 '0' => Ok(data::Register::V0),
                 '1' => Ok(data::Register::V1),
@@ -949,7 +2090,8 @@ impl Assembler {
                 'D' => Ok(data::Register::VD),
                 'E' => Ok(data::Register::VE),
                 'F' => Ok(data::Register::VF),
-                _ => panic!("Bad register index {}", schar)
+                _ => Err(AsmError::Register(RegError::new(rtext,
+                                                self.last_pos())))
             };
         }
     }
@@ -958,24 +2100,23 @@ impl Assembler {
     /// 
     /// # Returns
     ///
-    /// The assembled `SkipType`, wrapped in a `Result`
+    /// The assembled `SkipType`, wrapped in a `Result`.
+    /// The accepted mnemonics are listed in `constants::SKIP_CONDS`.
     fn skiptype(&mut self) -> Result<data::SkipType, AsmError> {
-        //save the current token
-        let save_token = self.cur_token.clone();
-
-        //get the next token
-        self.eat(&TokenType::SkipCond)?;
+        //get the next token, keeping its token
+        let save_token = self.eat(&TokenType::SkipCond)?;
 
         //get the skip string
-        let skstr = save_token.get_value().as_text().unwrap();
+        let skstr = save_token.get_value_ref().as_text_ref().unwrap();
 
         //and generate the skip condition
-        return match skstr.as_str() {
+        return match skstr {
             "EQ" => Ok(data::SkipType::Equals),
             "NE" => Ok(data::SkipType::NotEquals),
             "KD" => Ok(data::SkipType::KeyDown),
             "KU" => Ok(data::SkipType::KeyUp),
-            _ => panic!("Unknown skip type {}", skstr)
+            _ => Err(AsmError::Condition(CondError::new(skstr,
+                        self.last_pos())))
         };
     }
 
@@ -985,14 +2126,25 @@ impl Assembler {
     ///
     /// The assembled literal, wrapped in a `Result`
     fn dec_lit(&mut self) -> Result<u16, AsmError> {
-        //save the current token
-        let save_token = self.cur_token.clone();
-
-        //parse the literal
-        self.eat(&TokenType::DecLit)?;
+        //parse the literal, keeping its token
+        let save_token = self.eat(&TokenType::DecLit)?;
 
         //and return the token value
-        return Ok(save_token.get_value().as_word().unwrap())
+        return Ok(save_token.get_value_ref().as_word().unwrap())
+    }
+
+    /// Assembles a negative decimal integer literal
+    ///
+    /// # Returns
+    ///
+    /// The assembled literal, wrapped in a `Result`
+    fn neg_lit(&mut self) -> Result<i32, AsmError> {
+        //parse the literal, keeping its token
+        let save_token = self.eat(&TokenType::NegLit)?;
+
+        //and return the value of its two's complement
+        let w = save_token.get_value_ref().as_word().unwrap();
+        return Ok(i32::from(w as i16));
     }
 
     /// Assembles a hex integer literal
@@ -1001,38 +2153,101 @@ impl Assembler {
     ///
     /// The assembled literal, wrapped in a `Result`
     fn hex_lit(&mut self) -> Result<u16, AsmError> {
-        //save the current token
-        let save_token = self.cur_token.clone();
-
-        //parse the literal
-        self.eat(&TokenType::HexLit)?;
+        //parse the literal, keeping its token
+        let save_token = self.eat(&TokenType::HexLit)?;
 
         //and return the token value
-        return Ok(save_token.get_value().as_word().unwrap());
+        return Ok(save_token.get_value_ref().as_word().unwrap());
     }
 
     /// Assembles a binary literal
     ///
     /// # Returns
     ///
-    /// The assembled literal, wrapped in a `Result`
-    fn bin_lit(&mut self) -> Result<u8, AsmError> {
-        //save the current token
-        let save_token = self.cur_token.clone();
-
-        //parse the literal
-        self.eat(&TokenType::BinLit)?;
+    /// The assembled literal (a byte or a word, depending
+    /// on how many digits it has), wrapped in a `Result`
+    fn bin_lit(&mut self) -> Result<Variant, AsmError> {
+        //parse the literal, keeping its token
+        let save_token = self.eat(&TokenType::BinLit)?;
 
         //and return the token value
-        return Ok(save_token.get_value().as_byte().unwrap());
+        return Ok(save_token.get_value());
     }
 }
 
+/// Applies an arithmetic operator to two values
+///
+/// # Arguments
+///
+/// * `op` - The type of the operator token
+/// * `lhs` - The left-hand value and whether it is a word
+/// * `rhs` - The right-hand value and whether it is a word
+/// * `pos` - The position of the operator
+///
+/// # Returns
+///
+/// The result and whether it must be stored as a word, or an
+/// `AsmError` if the operation overflows or divides by zero
+fn apply_op(op: &TokenType, lhs: (i32, bool), rhs: (i32, bool),
+            pos: Position) -> Result<(i32, bool), AsmError> {
+    //check for division by zero
+    if (*op == TokenType::Slash) && (rhs.0 == 0) {
+        return Err(AsmError::Expression(
+                    ExprError::new("division by zero", pos)));
+    }
+
+    //apply the operator
+    let val = match *op {
+        TokenType::Plus => lhs.0.checked_add(rhs.0),
+        TokenType::Minus => lhs.0.checked_sub(rhs.0),
+        TokenType::Asterisk => lhs.0.checked_mul(rhs.0),
+        _ => lhs.0.checked_div(rhs.0)
+    };
+
+    //and check for overflow
+    return match val {
+        Some(v) if fits_word(v, lhs.0, rhs.0) => Ok((v, lhs.1 || rhs.1)),
+        _ => Err(AsmError::Expression(
+                    ExprError::new("arithmetic overflow", pos)))
+    };
+}
+
+/// Determines whether an undefined register alias looks like
+/// a mistyped register, such as `VG`, `V10`, or a lone `V`
+///
+/// # Argument
+///
+/// * `name` - The uppercase name of the alias
+///
+/// # Returns
+///
+/// Whether the name is a `V` followed by at most two characters
+fn is_reg_typo(name: &str) -> bool {
+    return name.starts_with('V') && (name.len() <= 3);
+}
+
+/// Gets the index of a register for use as an instruction operand
+///
+/// # Argument
+///
+/// * `reg` - The register to get the index of
+///
+/// # Returns
+///
+/// The register's index, from `0` for `V0` to `15` for `VF`
+fn reg_id(reg: &data::Register) -> u8 {
+    return reg.to_id() & 0x0F;
+}
+
 //unit tests
 #[cfg(test)]
 mod tests {
     //import the Assembler struct
     use super::*;
+    use super::super::super::util::Sigils;
+    use super::super::Section;
+    use super::super::super::error::Diagnostic;
+    use std::time::Instant;
 
     //define the program
     const CODE: &str = "CLS
@@ -1049,46 +2264,1426 @@ mod tests {
     #[test]
     fn test_codegen() {
         let mut asm = Assembler::new(CODE, "test.c8").unwrap();
-        let mut i = asm.instruction().unwrap();
-        match i {
-            Instruction::CLS => {},
-            _ => panic!("Expected CLS")
+        assert_eq!(asm.instruction().unwrap(), Instr::Cls);
+        assert_eq!(asm.instruction().unwrap(), Instr::MovConst(0, 0));
+        assert_eq!(asm.instruction().unwrap(), Instr::MovConst(1, 15));
+        assert_eq!(asm.instruction().unwrap(), Instr::Key(2));
+        assert_eq!(asm.instruction().unwrap(), Instr::Sch(2));
+        asm.eat(&TokenType::LblDef).unwrap();
+        assert_eq!(asm.instruction().unwrap(), Instr::Draw(0, 1, 5));
+        assert_eq!(asm.instruction().unwrap(), Instr::AddConst(0, 1));
+        assert_eq!(asm.instruction().unwrap(), Instr::Jmp(0x20A));
+    }
+
+    //this test checks that the assembled bytes match the written binary
+    #[test]
+    fn test_bytes_match_binary() {
+        let path = std::env::temp_dir().join("ch8alib_bytes_test.c8");
+        let name = path.to_string_lossy().into_owned();
+        let _ = std::fs::remove_file(&path);
+        let mut asm = Assembler::new(CODE, &name).unwrap();
+        asm.assemble().unwrap();
+        asm.write().unwrap();
+        let written = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(asm.get_bytes(), written.as_slice());
+    }
+
+    //this test checks that an existing binary only stops the
+    //assembler from writing over it, not from assembling
+    #[test]
+    fn test_existing_binary() {
+        let path = std::env::temp_dir().join("ch8alib_existing_test.c8");
+        let name = path.to_string_lossy().into_owned();
+        std::fs::write(&path, [0xFF]).unwrap();
+        let mut asm = Assembler::new(CODE, &name).unwrap();
+        assert!(asm.assemble().is_ok());
+        let res = asm.write();
+        std::fs::remove_file(&path).unwrap();
+        match res {
+            Err(AsmError::Binary(_be)) => {},
+            _ => panic!("Expected a binary error")
         };
-        i = asm.instruction().unwrap();
-        match i {
-            Instruction::MOV(_data) => {},
-            _ => panic!("Expected MOV")
+
+        let asm = Assembler::unnamed(CODE, &AsmOptions::new()).unwrap();
+        assert!(asm.write().is_err());
+    }
+
+    //this test checks assembling an empty source
+    #[test]
+    fn test_empty_source() {
+        let mut asm = Assembler::new("", "empty.c8").unwrap();
+        let bin = asm.assemble().unwrap();
+        assert_eq!(bin.len(), 0);
+    }
+
+    //this test checks separating statements on the same line
+    #[test]
+    fn test_separators() {
+        let code = "MOV V0, #0 \\ MOV V1,#0\\MOV V2, #0 ; a \\ comment\n\
+                    SKIP.KD V3 \\ $FF\nDB #1, #2 \\ ALIGN \\ _end:\n\
+                    MOV I, _end";
+        let mut asm = Assembler::new(code, "sep.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x60, 0x00, 0x61, 0x00, 0x62, 0x00,
+                                      0xE3, 0x9E, 0xFF, 0x01, 0x02, 0x00,
+                                      0xA2, 0x0C]);
+        assert!(asm.get_warnings().is_empty());
+
+        //errors point into the line and skip only their statement
+        let code = "CLS \\ MOV V0 #1 \\ CLS\nFOO V1";
+        let mut asm = Assembler::new(code, "sep.c8").unwrap();
+        let errs = asm.assemble_all().err().unwrap();
+        assert_eq!(errs.len(), 2);
+        assert!(format!("{}", errs[0]).starts_with("(1:14)"));
+        assert_eq!(asm.get_bytes(), &[0x00, 0xE0, 0x00, 0xE0]);
+    }
+
+    //this test checks continuing a data list onto later lines
+    #[test]
+    fn test_continuation() {
+        let code = "DW $0102, \\\n\
+                    \t$0304, \\ ; more words\n\
+                    \t$0506\r\n\
+                    _end:\nMOV I, _end";
+        let mut asm = Assembler::new(code, "cont.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+                                      0xA2, 0x06]);
+        assert!(asm.get_warnings().is_empty());
+
+        //errors on a continued line keep their own position
+        let code = "DW $0102, \\\n  V1";
+        let mut asm = Assembler::new(code, "cont.c8").unwrap();
+        let errs = asm.assemble_all().err().unwrap();
+        assert!(format!("{}", errs[0]).starts_with("(2:3)"));
+    }
+
+    //this test checks assembling a source that is only comments
+    #[test]
+    fn test_comment_only_source() {
+        for code in [";first column", ";a\n\t; b\r\n  ;c\n",
+                     ";{ a\nCLS\n;}", ";{\n;}\n; done\n\n"] {
+            let mut asm = Assembler::new(code, "notes.c8").unwrap();
+            assert_eq!(asm.assemble().unwrap().len(), 0);
+            assert!(asm.is_empty());
+            assert!(asm.get_warnings().is_empty());
+            assert_eq!(asm.get_addrs().get_size(), 0);
+        }
+    }
+
+    //this test checks collecting multiple errors
+    #[test]
+    fn test_assemble_all() {
+        let code = "MOV V0 #1
+                    CLS
+                    FOO V1
+                    _ok:
+                    DRAW I, V1, #1
+                    JMP _ok";
+        let mut asm = Assembler::new(code, "errs.c8").unwrap();
+        let errs = asm.assemble_all().err().unwrap();
+        assert_eq!(errs.len(), 3);
+        match errs[0] {
+            AsmError::Parser(_) => {},
+            _ => panic!("Expected a parser error")
         };
-        i = asm.instruction().unwrap();
-        match i {
-            Instruction::MOV(_data) => {},
-            _ => panic!("Expected MOV")
+        match errs[1] {
+            AsmError::Opcode(_) => {},
+            _ => panic!("Expected an opcode error")
         };
-        i = asm.instruction().unwrap();
-        match i {
-           Instruction::KEY(_data) => {},
-           _ => panic!("Expected KEY")
+        match errs[2] {
+            AsmError::Argument(_) => {},
+            _ => panic!("Expected an argument error")
         };
-        i = asm.instruction().unwrap();
-        match i {
-            Instruction::SCH(_data) => {},
-            _ => panic!("Expected SCH")
+    }
+
+    //this test checks collecting lexer errors on separate lines
+    #[test]
+    fn test_assemble_all_lexer_errors() {
+        let code = "$G1\nCLS\nMOV V0, @\n_end:\nJMP _end";
+        for pre in [true, false] {
+            let opts = AsmOptions::new().with_preprocess(pre);
+            let mut asm = Assembler::with_options(code, "lex.c8", &opts)
+                            .unwrap();
+            let errs = asm.assemble_all().err().unwrap();
+            assert_eq!(errs.len(), 2);
+            assert!(format!("{}", errs[0]).starts_with("(1:1)"));
+            assert!(format!("{}", errs[1]).starts_with("(3:9)"));
+        }
+    }
+
+    //this test checks writing the hex font with FONT_DATA
+    #[test]
+    fn test_font_data() {
+        //the canonical Chip-8 font
+        let font: [u8; 80] = [
+            0xF0, 0x90, 0x90, 0x90, 0xF0, 0x20, 0x60, 0x20, 0x20, 0x70,
+            0xF0, 0x10, 0xF0, 0x80, 0xF0, 0xF0, 0x10, 0xF0, 0x10, 0xF0,
+            0x90, 0x90, 0xF0, 0x10, 0x10, 0xF0, 0x80, 0xF0, 0x10, 0xF0,
+            0xF0, 0x80, 0xF0, 0x90, 0xF0, 0xF0, 0x10, 0x20, 0x40, 0x40,
+            0xF0, 0x90, 0xF0, 0x90, 0xF0, 0xF0, 0x90, 0xF0, 0x10, 0xF0,
+            0xF0, 0x90, 0xF0, 0x90, 0x90, 0xE0, 0x90, 0xE0, 0x90, 0xE0,
+            0xF0, 0x80, 0x80, 0x80, 0xF0, 0xE0, 0x90, 0x90, 0x90, 0xE0,
+            0xF0, 0x80, 0xF0, 0x80, 0xF0, 0xF0, 0x80, 0xF0, 0x80, 0x80];
+
+        //the font is written in place and labeled
+        let code = "MOV I, _font_data\nJMP _end\nfont_data\n_end:\nCLS";
+        let mut asm = Assembler::new(code, "font.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(&asm.get_bytes()[0..4], &[0xA2, 0x04, 0x12, 0x54]);
+        assert_eq!(&asm.get_bytes()[4..84], &font[..]);
+        assert_eq!(&asm.get_bytes()[84..], &[0x00, 0xE0]);
+        assert_eq!(asm.get_addrs().get_entry("_font_data").unwrap(), 0x0204);
+
+        //the label is defined the same way without preprocessing
+        let opts = AsmOptions::new().with_preprocess(false);
+        let code = "CLS\nFONT_DATA\nMOV I, _font_data";
+        let mut asm = Assembler::unnamed(code, &opts).unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(&asm.get_bytes()[82..], &[0xA2, 0x02]);
+
+        //and a label the code defines itself is kept
+        let code = "_font_data:\nCLS\nFONT_DATA\nMOV I, _font_data";
+        let mut asm = Assembler::new(code, "font.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(&asm.get_bytes()[82..], &[0xA2, 0x00]);
+    }
+
+    //this test checks writing string literals in data lists
+    #[test]
+    fn test_str_lits() {
+        let code = "DB \"a;b,c.\", #0 ; a comment\n\
+                    DB \"//\", \"\\\"\n_end:\nMOV I, _end";
+        let mut asm = Assembler::new(code, "str.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[b'a', b';', b'b', b',', b'c', b'.',
+                                      0x00, b'/', b'/', b'\\', 0xA2, 0x0A]);
+        assert!(asm.get_warnings().is_empty());
+
+        //the comment marker can be changed without affecting strings
+        let opts = AsmOptions::new().with_sigils(
+                        &Sigils::new().with_comment("//"));
+        let code = "DB \"a//b;\" // a comment";
+        let mut asm = Assembler::unnamed(code, &opts).unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), b"a//b;");
+
+        //strings are not words
+        let mut asm = Assembler::new("DW \"ab\"", "str.c8").unwrap();
+        assert!(asm.assemble().is_err());
+    }
+
+    //this test checks that registers may be written in lowercase
+    #[test]
+    fn test_lowercase_registers() {
+        let pairs = [("mov v0, v1", "MOV V0, V1"),
+                     ("mov i, #3\nadd i, vc", "MOV I, #3\nADD I, VC"),
+                     ("draw va, vB, #5", "DRAW VA, VB, #5"),
+                     ("ctr reg ve\nadd ctr, #1", "CTR REG VE\nADD CTR, #1"),
+                     ("skip.eq vf, v0\nrand v3, $FF",
+                      "SKIP.EQ VF, V0\nRAND V3, $FF")];
+        for (lower, upper) in pairs.iter() {
+            let mut asm = Assembler::new(lower, "lower.c8").unwrap();
+            asm.assemble().unwrap();
+            let mut expected = Assembler::new(upper, "upper.c8").unwrap();
+            expected.assemble().unwrap();
+            assert_eq!(asm.get_bytes(), expected.get_bytes(),
+                       "for {}", lower);
+        }
+
+        //lowercase register typos are reported as registers
+        let mut asm = Assembler::new("mov vg, #1", "lower.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Register(_)) => {},
+            _ => panic!("Expected a register error")
         };
-        asm.eat(&TokenType::LblDef).unwrap();
-        i = asm.instruction().unwrap();
-        match i {
-            Instruction::DRAW(_data) => {},
-            _ => panic!("Expected DRAW")
+
+        //and the I register is still caught where it is not allowed
+        let mut asm = Assembler::new("draw i, v1, #1", "lower.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Argument(_)) => {},
+            _ => panic!("Expected an argument error")
         };
-        i = asm.instruction().unwrap();
-        match i {
-            Instruction::ADD(_data) => {},
-            _ => panic!("Expected ADD")
+    }
+
+    //this test checks that undefined labels are all
+    //reported before anything is assembled
+    #[test]
+    fn test_undefined_labels() {
+        let code = "JMP _nowhere\nCLS\nFOO V1\n_ok:\nCALL _ok\n\
+                    MOV I, _gone + #2";
+        let mut asm = Assembler::new(code, "undef.c8").unwrap();
+        let errs = asm.assemble_all().err().unwrap();
+        assert_eq!(errs.len(), 2);
+        assert!(format!("{}", errs[0]).starts_with(
+                "(1:5): Could not get the address of the label _nowhere"));
+        assert!(format!("{}", errs[1]).starts_with(
+                "(6:8): Could not get the address of the label _gone"));
+        assert_eq!(errs[1].get_column(), Some(8));
+        assert!(asm.is_empty());
+
+        //assembling stops at the first one
+        let mut asm = Assembler::new(code, "undef.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Address(ae)) => {
+                assert_eq!(ae.get_line(), Some(1));
+            },
+            _ => panic!("Expected an address error")
+        };
+
+        //a reference at the start of the code is not a definition
+        let code = "DW _data\nCLS\n_data:";
+        let mut asm = Assembler::new(code, "undef.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x02, 0x04, 0x00, 0xE0]);
+    }
+
+    //this test checks that errors show the offending source line
+    #[test]
+    fn test_error_source_lines() {
+        let code = "CLS\nMOV V0 #1\n\tFOO V1";
+        let mut asm = Assembler::new(code, "srcln.c8").unwrap();
+        let errs = asm.assemble_all().err().unwrap();
+        let msgs: Vec<String> = errs.iter()
+                                    .map(|e| format!("{}", e))
+                                    .collect();
+        assert_eq!(msgs, vec![
+            "(2:8): while assembling MOV: expected comma, \
+             found decimal literal\n    \
+             MOV V0 #1\n           ^",
+            "(3:2): unknown instruction FOO\n    \tFOO V1\n    \t^"
+        ]);
+        let mut asm = Assembler::new("CLS\r\nMOV V1, @", "srcln.c8")
+                        .unwrap();
+        assert_eq!(format!("{}", asm.assemble().err().unwrap()),
+                   "(2:9): unknown character @\n    MOV V1, @\n            ^");
+    }
+
+    //this test checks that mixed-case code assembles identically
+    #[test]
+    fn test_mixed_case() {
+        let upper = "_START:\nCLS\nMOV V0, #1\nMOV I, _SPR\n\
+                     SKIP.EQ V0, $FF\nADD V0, V1\nDRAW V0, V1, #1\n\
+                     JMP _START\n_SPR:\nDB 0XAB, %1010";
+        let lower = "_start:\ncls\nmov v0, #1\nmov i, _spr\n\
+                     skip.eq v0, $ff\nadd v0, v1\ndraw v0, v1, #1\n\
+                     jmp _start\n_spr:\ndb 0xab, %1010";
+        let mixed = "_Start:\nCls\nMov V0, #1\nMoV I, _Spr\n\
+                     Skip.Eq v0, $fF\nAdd V0, v1\nDraw v0, V1, #1\n\
+                     Jmp _START\n_spr:\nDb 0Xab, %1010";
+        let mut asm = Assembler::new(upper, "upper.c8").unwrap();
+        asm.assemble().unwrap();
+        let expected = asm.get_bytes().to_vec();
+        for code in [lower, mixed] {
+            let mut asm = Assembler::new(code, "case.c8").unwrap();
+            asm.assemble().unwrap();
+            assert_eq!(asm.get_bytes(), expected.as_slice());
+        }
+    }
+
+    //this test checks that label errors keep the label's spelling
+    #[test]
+    fn test_label_spelling() {
+        let code = "_Start:\nCLS\nJMP _START\nJMP _strt";
+        let mut asm = Assembler::new(code, "spell.c8").unwrap();
+        let errs = asm.assemble_all().err().unwrap();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(format!("{}", errs[0]),
+                   "(4:5): Could not get the address of the label _strt\n\
+                    \x20   JMP _strt\n\
+                    \x20       ^");
+        let syms: Vec<(&str, u16)> = asm.get_addrs().iter().collect();
+        assert_eq!(syms, vec![("_Start", 0x0200)]);
+    }
+
+    //this test checks tracking the write address
+    #[test]
+    fn test_get_address() {
+        let mut asm = Assembler::new(CODE, "addr.c8").unwrap();
+        assert_eq!(asm.get_address(), 0x0200);
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_address(), 0x0210);
+    }
+
+    //this test checks assembling literals on CRLF-terminated lines
+    #[test]
+    fn test_crlf_literals() {
+        let code = "_spr:\r\n\t%11000011\r\n\t%0110\r\n\tDB %1,\t%10\r\n";
+        let mut asm = Assembler::new(code, "crlf.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0xC3, 0x06, 0x01, 0x02]);
+        assert!(asm.get_warnings().is_empty());
+    }
+
+    //this test checks naming the binary only when it is written
+    #[test]
+    fn test_write_to() {
+        let mut asm = Assembler::unnamed("CLS\nRET", &AsmOptions::new())
+                        .unwrap();
+        asm.assemble().unwrap();
+
+        //write the binary somewhere that does not exist yet
+        let file = format!("ch8asm_write_{}.c8", std::process::id());
+        let path = std::env::temp_dir().join(file);
+        let name = path.to_str().unwrap();
+        assert_eq!(asm.write_to(name).unwrap(), 4);
+        assert_eq!(std::fs::read(&path).unwrap(),
+                   vec![0x00, 0xE0, 0x00, 0xEE]);
+
+        //existing files are never overwritten
+        assert!(asm.write_to(name).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    //this test checks getting the length of the assembled binary
+    #[test]
+    fn test_len() {
+        let mut asm = Assembler::new(CODE, "len.c8").unwrap();
+        assert!(asm.is_empty());
+        asm.assemble().unwrap();
+        assert!(!asm.is_empty());
+        assert_eq!(asm.len(), 16);
+        assert_eq!(asm.get_instr_count(), 8);
+
+        //data is not counted as instructions
+        let mut asm = Assembler::new("CLS\n$FF $FE", "len.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!((asm.len(), asm.get_instr_count()), (4, 1));
+
+        //programs with no code assemble to nothing
+        let mut asm = Assembler::new("; nothing", "len.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.len(), 0);
+    }
+
+    //this test checks the listing of assembled code
+    #[test]
+    fn test_listing() {
+        let code = "CLS\n_loop:\n    MOV V0, #5\n    $ABCD\n    JMP _loop";
+        let mut asm = Assembler::new(code, "lst.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(format!("{}", asm.get_listing()),
+                   "0200  00E0   CLS\n\
+                    0202         _loop:\n\
+                    0202  6005   MOV V0, #5\n\
+                    0204  ABCD   $ABCD\n\
+                    0206  1202   JMP _loop\n");
+    }
+
+    //this test checks that the listing shows the opcodes
+    //of RAND instructions the same way on every run
+    #[test]
+    fn test_rand_listing() {
+        let code = "_start:\nRAND V1, $0F\nRAND VA, #200\nJMP _start";
+        let mut first = Assembler::new(code, "rand.c8").unwrap();
+        first.assemble().unwrap();
+        let mut second = Assembler::new(code, "rand.c8").unwrap();
+        second.assemble().unwrap();
+        let lst = format!("{}", first.get_listing());
+        assert_eq!(lst, format!("{}", second.get_listing()));
+        assert_eq!(lst, "0200         _start:\n\
+                         0200  C10F   RAND V1, $0F\n\
+                         0202  CAC8   RAND VA, #200\n\
+                         0204  1200   JMP _start\n");
+    }
+
+    //this test checks assembling with register aliases
+    #[test]
+    fn test_register_aliases() {
+        let code = "MOV LATER, #2\n\
+                    COUNTER REG V3\n\
+                    speed reg va\n\
+                    MOV COUNTER, #1\n\
+                    ADD COUNTER, SPEED\n\
+                    SKIP.EQ COUNTER, SPEED\n\
+                    _end:\n\
+                    JMP _end\n\
+                    LATER REG V5";
+        let mut asm = Assembler::new(code, "alias.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x65, 0x02, 0x63, 0x01, 0x83, 0xA4,
+                                      0x53, 0xA0, 0x12, 0x08]);
+    }
+
+    //this test checks that mistyped registers are errors
+    #[test]
+    fn test_bad_registers() {
+        for (code, name) in [("MOV VG, #1", "VG"), ("MOV V0, V", "V"),
+                             ("ADD V10, V1", "V10"), ("SHL V", "V")] {
+            let mut asm = Assembler::new(code, "regs.c8").unwrap();
+            match asm.assemble() {
+                Err(AsmError::Register(re)) => {
+                    assert!(format!("{}", re)
+                            .contains(&format!("bad register {} ", name)));
+                },
+                _ => panic!("Expected a register error for {}", code)
+            };
+        }
+    }
+
+    //this test checks that undefined register aliases are errors
+    #[test]
+    fn test_undefined_alias() {
+        let mut asm = Assembler::new("COUNT REG V1\nMOV COUNTR, #1",
+                                     "alias.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Alias(ae)) => {
+                assert_eq!(ae.get_line(), 2);
+                assert!(format!("{}", ae)
+                        .starts_with("(2:5): undefined register alias \
+                                      COUNTR"));
+            },
+            _ => panic!("Expected an alias error")
+        };
+    }
+
+    //this test checks assembling arithmetic expressions
+    #[test]
+    fn test_expressions() {
+        let code = "MOV V0, #10+#5\n\
+                    MOV I, _base+#2\n\
+                    MOV V1, (#2+#3)*#4\n\
+                    MOV V2, #2+#3*#4\n\
+                    #250+#10\n\
+                    _base:\n\
+                    DS #12/#2\n\
+                    _end:\n\
+                    MOV I, _end-#1";
+        let mut asm = Assembler::new(code, "expr.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x60, 0x0F, 0xA2, 0x0C, 0x61, 0x14,
+                                      0x62, 0x0E, 0x01, 0x04, 0x00, 0x00,
+                                      0x00, 0x00, 0x00, 0x00, 0xA2, 0x0F]);
+    }
+
+    //this test checks that bad arithmetic is an error
+    #[test]
+    fn test_expression_errors() {
+        for (code, msg) in [("MOV V0, #1/#0", "(1:11): division by zero"),
+                            ("MOV V0, #1-#2", "(1:11): arithmetic overflow"),
+                            ("MOV V0, $FFFF+#1",
+                             "(1:14): arithmetic overflow"),
+                            ("MOV V0, (",
+                             "(1:9): expected a value but found EOF"),
+                            ("MOV V0, #1+",
+                             "(1:11): expected a value but found EOF"),
+                            ("DB #2*, #3",
+                             "(1:7): expected a value but found comma"),
+                            ("JMP (#2+)",
+                             "(1:9): expected a value but found right \
+                              parenthesis")] {
+            let mut asm = Assembler::new(code, "expr.c8").unwrap();
+            match asm.assemble() {
+                Err(AsmError::Expression(ee)) => {
+                    assert!(format!("{}", ee).starts_with(msg), "{}", ee);
+                },
+                res => panic!("Expected an expression error: {:?}", res)
+            };
+        }
+    }
+
+    //this test checks building a jump table with label offsets
+    #[test]
+    fn test_jump_table() {
+        let code = "_table:\n\
+                    DW _first, _second+#2\n\
+                    MOV I, _table+#2\n\
+                    _first:\n\
+                    CLS\n\
+                    _second:\n\
+                    RET\n\
+                    RET";
+        let mut asm = Assembler::new(code, "table.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x02, 0x06, 0x02, 0x0A, 0xA2, 0x02,
+                                      0x00, 0xE0, 0x00, 0xEE, 0x00, 0xEE]);
+    }
+
+    //this test checks data directives that refer to
+    //labels defined after them
+    #[test]
+    fn test_data_forward_refs() {
+        let code = "JMP _main\n\
+                    _table:\n\
+                    DW _a, _b,\n   _c\n\
+                    DB _c-_a, #0\n\
+                    _main:\n\
+                    MOV I, _table\n\
+                    _a:\nCLS\n_b:\nCLS\n_c:\nRET";
+        let mut asm = Assembler::new(code, "fwd.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x12, 0x0A, 0x02, 0x0C, 0x02, 0x0E,
+                                      0x02, 0x10, 0x04, 0x00, 0xA2, 0x02,
+                                      0x00, 0xE0, 0x00, 0xE0, 0x00, 0xEE]);
+        assert_eq!(asm.get_addrs().get_entry("_main").unwrap(), 0x020A);
+    }
+
+    //this test checks that addresses past 12 bits are errors
+    #[test]
+    fn test_address_range() {
+        let mut asm = Assembler::new("_a:\nJMP _a+$FFF", "far.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Range(re)) => {
+                assert!(format!("{}", re).starts_with(
+                        "(2:5): value $11FF does not fit in 12 bits"));
+            },
+            _ => panic!("Expected a range error")
+        };
+    }
+
+    //this test checks rejecting oversized instruction fields
+    #[test]
+    fn test_field_range() {
+        let code = "DRAW V0, V1, #15\nRAND V2, $FF\n\
+                    DRAW V0, V1, #20\nRAND V2, #300";
+        let mut asm = Assembler::new(code, "fields.c8").unwrap();
+        let errs = asm.assemble_all().err().unwrap();
+        let msgs: Vec<String> = errs.iter()
+                                    .map(|e| format!("{}", e))
+                                    .collect();
+        assert_eq!(msgs.len(), 2);
+        assert!(msgs[0].starts_with(
+                "(3:14): value $14 does not fit in 4 bits"));
+        assert!(msgs[1].starts_with(
+                "(4:10): value $12C does not fit in 8 bits"));
+        assert_eq!(asm.get_bytes(), &[0xD0, 0x1F, 0xC2, 0xFF]);
+    }
+
+    //this test checks warning about truncated constants
+    #[test]
+    fn test_truncation_warnings() {
+        let code = "SKIP.EQ V0, #300\nADD V1, $FF\nDB #1, $1FF";
+        let mut asm = Assembler::new(code, "trunc.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x30, 0x2C, 0x71, 0xFF,
+                                      0x01, 0xFF]);
+        let msgs: Vec<String> = asm.get_warnings().iter()
+                                    .map(|w| format!("{}", w))
+                                    .collect();
+        assert_eq!(msgs, vec![
+            "(1:13): warning: value $12C truncated to 8 bits\n    \
+             SKIP.EQ V0, #300\n                ^",
+            "(3:8): warning: value $1FF truncated to 8 bits\n    \
+             DB #1, $1FF\n           ^"
+        ]);
+    }
+
+    //this test checks loading the I register from labels and
+    //literal addresses
+    #[test]
+    fn test_mov_addresses() {
+        let code = "MOV I, $300\nMOV I, 0x2A4\nMOV I, _spr\n\
+                    MOV I, _spr+#2\nMOV V0, $0FF\n_spr:\nDB $FF";
+        let mut asm = Assembler::new(code, "mov.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0xA3, 0x00, 0xA2, 0xA4, 0xA2, 0x0A,
+                                      0xA2, 0x0C, 0x60, 0xFF, 0xFF]);
+
+        //a data register cannot hold a value past $FF, however it's written
+        for code in ["MOV V0, $300", "MOV V1, 0x2A4", "MOV V2, $1FF",
+                     "MOV V3, #4000", "MOV V4, #256", "MOV V5, %100000000"] {
+            let mut asm = Assembler::new(code, "mov.c8").unwrap();
+            match asm.assemble() {
+                Err(AsmError::Range(re)) => {
+                    assert!(format!("{}", re).starts_with("(1:9): value"));
+                },
+                _ => panic!("Expected a range error for {}", code)
+            };
+        }
+
+        //and the I register cannot hold a value past $FFF
+        let mut asm = Assembler::new("MOV I, $1000", "mov.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Range(re)) => {
+                assert!(format!("{}", re).starts_with("(1:8): value"));
+            },
+            _ => panic!("Expected a range error for MOV I, $1000")
+        };
+    }
+
+    //this test checks assembling negative decimal literals
+    #[test]
+    fn test_negative_literals() {
+        let code = "MOV V0, #-1\nADD V1, #-128\nDB #-1, #-2\n#-3\n_end:\n\
+                    MOV I, _end";
+        let mut asm = Assembler::new(code, "neg.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x60, 0xFF, 0x71, 0x80,
+                                      0xFF, 0xFE, 0xFD, 0xA2, 0x07]);
+        assert!(asm.get_warnings().is_empty());
+
+        //words hold negatives down to -32768
+        let code = "DW #-1, #-32768\n#-300\n_end:\nMOV I, _end";
+        let mut asm = Assembler::new(code, "neg.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0xFF, 0xFF, 0x80, 0x00,
+                                      0xFE, 0xD4, 0xA2, 0x06]);
+
+        //while byte fields only hold a signed byte
+        for code in ["MOV V0, #-300", "DB #-129", "ADD V1, #-300"] {
+            let mut asm = Assembler::new(code, "neg.c8").unwrap();
+            match asm.assemble() {
+                Err(AsmError::Range(_)) => {},
+                _ => panic!("Expected a range error for {}", code)
+            };
+        }
+    }
+
+    //this test checks assembling source written in another dialect
+    #[test]
+    fn test_sigils() {
+        let sig = Sigils::new().with_comment("//").with_hex('&');
+        let opts = AsmOptions::new().with_sigils(&sig);
+        let code = "MOV V0, 0d10 // ten\nADD V0, &F//{ block\n//}\n\
+                    DB 0b1, 0x2 // data";
+        let mut asm = Assembler::with_options(code, "dialect.c8",
+                                              &opts).unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x60, 0x0A, 0x70, 0x0F,
+                                      0x01, 0x02]);
+
+        //the default comment marker is no longer a comment
+        let mut asm = Assembler::with_options("CLS ; note", "dialect.c8",
+                                              &opts).unwrap();
+        assert!(asm.assemble().is_err());
+
+        //and markers that collide are rejected
+        let opts = AsmOptions::new().with_sigils(
+                        &Sigils::new().with_comment("$"));
+        match Assembler::with_options("CLS", "dialect.c8", &opts) {
+            Err(AsmError::Sigil(se)) => assert_eq!(se.get_marker(), "$"),
+            _ => panic!("Expected a sigil error")
+        };
+    }
+
+    //this test checks warning about unmatched calls and returns
+    #[test]
+    fn test_check_calls() {
+        let code = "CALL _sub\nCALL _data\nSKIP.EQ V0, #1\nRET\n\
+                    JMP _end\n_sub:\nRET\n_data:\n$FF\n_end:\nEXIT";
+        let mut asm = Assembler::new(code, "calls.c8").unwrap();
+        asm.assemble().unwrap();
+        asm.check_calls();
+        let msgs: Vec<String> = asm.get_warnings().iter()
+                                    .map(|w| format!("{}", w))
+                                    .collect();
+        assert_eq!(msgs, vec![
+            "(2:1): warning: CALL to $20C, which is not an instruction\n    \
+             CALL _data\n    ^",
+            "(4:1): warning: RET with no matching CALL\n    RET\n    ^"
+        ]);
+
+        //well-formed subroutines give no warnings
+        let code = "_loop:\nCALL _sub\nJMP _loop\n_sub:\nRET";
+        let mut asm = Assembler::new(code, "calls.c8").unwrap();
+        asm.assemble().unwrap();
+        asm.check_calls();
+        assert!(asm.get_warnings().is_empty());
+    }
+
+    //this test checks that errors name operands as they are written
+    #[test]
+    fn test_operand_names() {
+        let mut asm = Assembler::new("SKIP.KD VA V1", "names.c8").unwrap();
+        let msg = format!("{}", asm.assemble().err().unwrap());
+        assert!(msg.contains("Bad skip type KD"), "{}", msg);
+        let mut asm = Assembler::new("DRAW I, V1, #1", "names.c8").unwrap();
+        let msg = format!("{}", asm.assemble().err().unwrap());
+        assert!(msg.contains("bad argument I for DRAW"), "{}", msg);
+    }
+
+    //this test checks that using the I register where a V register
+    //is needed explains where I can be used
+    #[test]
+    fn test_i_register_guidance() {
+        for code in &["SND I", "ADD V1, I", "SKIP.EQ I, #1", "MOV DELAY, I"] {
+            let mut asm = Assembler::new(code, "guide.c8").unwrap();
+            let msg = format!("{}", asm.assemble().err().unwrap());
+            assert!(msg.contains("I is only valid for index operations, \
+                                  MOV I, NNN and ADD I, VX; \
+                                  use V0 to VF here"), "{}", msg);
+        }
+
+        //other bad registers get no guidance about I
+        let mut asm = Assembler::new("MOV V1, _lbl\n_lbl:", "guide.c8")
+                                    .unwrap();
+        let msg = format!("{}", asm.assemble().err().unwrap());
+        assert!(msg.contains("bad argument V1 for MOV"), "{}", msg);
+        assert!(!msg.contains("index operations"), "{}", msg);
+    }
+
+    //this test checks warning about programs that start with data
+    #[test]
+    fn test_check_entry() {
+        let code = "_spr:\n$FF $81\n_start:\nCLS\nJMP _start";
+        let mut asm = Assembler::new(code, "entry.c8").unwrap();
+        asm.assemble().unwrap();
+        asm.check_entry();
+        let msgs: Vec<String> = asm.get_warnings().iter()
+                                    .map(|w| format!("{}", w))
+                                    .collect();
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].starts_with("(2:1): warning: program starts \
+                                     with data"));
+
+        //jumping over the data gives no warning,
+        //and neither does an empty program
+        for code in ["JMP _start\n_spr:\n$FF $81\n_start:\nCLS",
+                     "; nothing"] {
+            let mut asm = Assembler::new(code, "entry.c8").unwrap();
+            asm.assemble().unwrap();
+            asm.check_entry();
+            assert!(asm.get_warnings().is_empty());
+        }
+    }
+
+    //this test checks that strict mode rejects stray tokens
+    #[test]
+    fn test_strict_mode() {
+        let opts = AsmOptions::new().with_strict(true);
+        for (code, msg) in [("CLS\n, CLS", "(2:1): unexpected comma"),
+                            ("V3\nCLS", "(1:1): unexpected register")] {
+            let mut asm = Assembler::with_options(code, "strict.c8", &opts)
+                            .unwrap();
+            match asm.assemble() {
+                Err(AsmError::Stray(se)) => {
+                    assert!(format!("{}", se).starts_with(msg));
+                },
+                _ => panic!("Expected a stray token error for {}", code)
+            };
+        }
+
+        //well-formed code still assembles
+        let mut asm = Assembler::with_options("_a:\nCLS\nJMP _a",
+                                              "strict.c8", &opts).unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x00, 0xE0, 0x12, 0x00]);
+    }
+
+    //this test checks that stray tokens cannot stall assembly
+    #[test]
+    fn test_stray_tokens() {
+        //stray tokens are skipped with a warning
+        let code = ", CLS\nV3\nRET";
+        let mut asm = Assembler::new(code, "stray.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x00, 0xE0, 0x00, 0xEE]);
+        let msgs: Vec<String> = asm.get_warnings().iter()
+                                    .map(|w| format!("{}", w))
+                                    .collect();
+        assert_eq!(msgs, vec![
+            "(1:1): warning: ignoring unexpected comma\n    , CLS\n    ^",
+            "(2:1): warning: ignoring unexpected register\n    V3\n    ^"
+        ]);
+
+        //and are an error in strict mode
+        let opts = AsmOptions::new().with_strict(true);
+        let mut asm = Assembler::with_options(code, "stray.c8", &opts)
+                        .unwrap();
+        assert!(asm.assemble().is_err());
+    }
+
+    //this test checks noting instructions that do nothing
+    #[test]
+    fn test_no_op_notes() {
+        let code = "MOV V3, V3\nADD V1, #0\nMOV V3, V4\nADD V1, #1";
+        let mut asm = Assembler::new(code, "noop.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x83, 0x30, 0x71, 0x00,
+                                      0x83, 0x40, 0x71, 0x01]);
+        let msgs: Vec<String> = asm.get_warnings().iter()
+                                    .map(|w| format!("{}", w))
+                                    .collect();
+        assert_eq!(msgs, vec![
+            "(1:1): warning: moving a register to itself has no effect\n    \
+             MOV V3, V3\n    ^",
+            "(2:1): warning: adding zero has no effect\n    \
+             ADD V1, #0\n    ^"
+        ]);
+    }
+
+    //this test checks adding a register to the I register
+    #[test]
+    fn test_add_index() {
+        let code = "ADD I, V5\nADD I, VF\nADD V5, V1";
+        let mut asm = Assembler::new(code, "add.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0xF5, 0x1E, 0xFF, 0x1E, 0x85, 0x14]);
+
+        //constants cannot be added to the I register
+        let mut asm = Assembler::new("ADD I, #1", "add.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Argument(_)) => {},
+            _ => panic!("Expected an argument error")
         };
-        i = asm.instruction().unwrap();
-        match i {
-            Instruction::JMP(_data) => {},
-            _ => panic!("Expected JMP")
+    }
+
+    //this test checks moving registers to and from the timers
+    #[test]
+    fn test_timer_moves() {
+        let code = "MOV V3, DELAY\nMOV DELAY, V4\nMOV sound, V5\n\
+                    GDL V3\nSDL V4\nSND V5";
+        let mut asm = Assembler::new(code, "timers.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0xF3, 0x07, 0xF4, 0x15, 0xF5, 0x18,
+                                      0xF3, 0x07, 0xF4, 0x15, 0xF5, 0x18]);
+
+        //the sound timer cannot be read
+        let mut asm = Assembler::new("MOV V0, SOUND", "timers.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Parser(_)) => {},
+            _ => panic!("Expected a parser error")
+        };
+    }
+
+    //this test checks assembling local labels
+    #[test]
+    fn test_local_labels() {
+        let code = "_draw:\n.loop:\nCLS\nJMP .loop\n\
+                    _erase:\n.loop:\nSKIP.EQ V0, #1\nJMP .loop";
+        let mut asm = Assembler::new(code, "local.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x00, 0xE0, 0x12, 0x00,
+                                      0x30, 0x01, 0x12, 0x04]);
+
+        //local labels cannot be used from another scope
+        let code = "_draw:\n.loop:\nCLS\n_erase:\nJMP .loop";
+        let mut asm = Assembler::new(code, "local.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Address(ae)) => {
+                assert!(format!("{}", ae).contains("_erase.loop"));
+            },
+            _ => panic!("Expected an address error")
+        };
+    }
+
+    //this test checks assembling the key skip conditions
+    #[test]
+    fn test_key_skip_conds() {
+        let mut asm = Assembler::new("SKIP.KD V0\nSKIP.KU V1",
+                                        "test.c8").unwrap();
+        assert_eq!(asm.instruction().unwrap(), Instr::SkipKeyDown(0));
+        assert_eq!(asm.instruction().unwrap(), Instr::SkipKeyUp(1));
+    }
+
+    //this test checks retrieving the label addresses
+    #[test]
+    fn test_get_addrs() {
+        let mut asm = Assembler::new(CODE, "syms.c8").unwrap();
+        asm.assemble().unwrap();
+        let syms: Vec<(&str, u16)> = asm.get_addrs().iter().collect();
+        assert_eq!(syms, vec![("_loop", 0x020A)]);
+    }
+
+    //assembles a generated source that repeats a block of
+    //three instructions, returning the number of bytes
+    fn assemble_blocks(blocks: usize) -> usize {
+        let src = format!("_loop:\n{}",
+                          "MOV V1, V2\nSKIP.EQ V1, $FF\nJMP _loop\n"
+                            .repeat(blocks));
+        let mut asm = Assembler::new(&src, "large.c8").unwrap();
+        return asm.assemble().unwrap().len();
+    }
+
+    //this test checks assembling a large source
+    #[test]
+    fn test_large_source() {
+        assert_eq!(assemble_blocks(500), 500 * 6);
+    }
+
+    //this test checks that assembly time grows linearly with the source
+    //(it depends on the speed of the machine, so it is only run
+    //on request with `cargo test -- --ignored`)
+    #[test]
+    #[ignore]
+    fn test_linear_assembly() {
+        let start = Instant::now();
+        assemble_blocks(120);
+        let small = start.elapsed();
+        let start = Instant::now();
+        assemble_blocks(480);
+        let large = start.elapsed();
+        assert!(large < small * 8, "{:?} vs {:?}", large, small);
+    }
+
+    //this test checks that the preprocessor places every label at
+    //the address the assembler reaches it at, over generated programs
+    #[test]
+    fn test_prep_equivalence() {
+        //the statements that programs are built from
+        const STMTS: [&str; 30] = [
+            "CLS", "RET", "MOV V1, V2", "MOV V0, #255", "MOV V0, $F",
+            "MOV I, _l0", "MOV I, _l1+#2", "ADD V3, #1", "ADD I, V3",
+            "DRAW V0, V1, #5", "SKIP.EQ V0, #300", "SKIP.KD V2",
+            "JMP _l0", "CALL _l1", "JPC #4", "MOV DELAY, V2",
+            "MOV COUNT, #2", "$F", "$FF", "$FFF", "#255", "#256",
+            "%1010", "%101010101", "0x1F", "#250+#10", "(#1+#2)*#3",
+            "DB #1, $FF, %1", "DW _l0, #3", "DS #3"
+        ];
+
+        //generate and check each program
+        let mut seed: u32 = 0x1234_5678;
+        for _prog in 0..200 {
+            let mut src = String::from("COUNT REG V4\n_l0:\n");
+            let mut labels = 2;
+            for _stmt in 0..40 {
+                //advance the generator
+                seed = seed.wrapping_mul(1_103_515_245)
+                           .wrapping_add(12_345);
+                let pick = (seed >> 16) as usize;
+
+                //and add a statement (ending its line or separated from
+                //the next one), a label, or an alignment
+                match pick % 8 {
+                    0 => {
+                        src.push_str(&format!("_l{}:\n", labels));
+                        labels += 1;
+                    },
+                    1 => {
+                        src.push_str(&format!(".l{}:\n", labels));
+                        labels += 1;
+                    },
+                    2 if pick.is_multiple_of(3) => src.push_str("ALIGN #4\n"),
+                    _ => {
+                        src.push_str(STMTS[(pick / 8) % STMTS.len()]);
+                        if pick.is_multiple_of(5) {
+                            src.push_str(" \\ ");
+                        } else {
+                            src.push('\n');
+                        }
+                    }
+                };
+            }
+            src.push_str("_l1:\n");
+
+            //assemble the program
+            let mut asm = Assembler::new(&src, "equiv.c8").unwrap();
+            asm.assemble().unwrap();
+
+            //and compare each label (local ones in the scope of the
+            //global label before them) to where its line was assembled
+            //(a label ends its line, so it is at the line's last entry)
+            let entries: Vec<_> = asm.get_listing().iter().collect();
+            let mut scope = String::new();
+            for (i, &(addr, _, line)) in entries.iter().enumerate() {
+                let last = entries.get(i + 1).is_none_or(|e| e.2 != line);
+                let stmt = line.rsplit(" \\ ").next().unwrap();
+                if !last || !stmt.ends_with(':') {
+                    continue;
+                }
+                let lbl = &stmt[..stmt.len() - 1];
+                if lbl.starts_with('_') {
+                    scope = String::from(lbl);
+                } else if !lbl.starts_with(constants::LOCAL_LABEL_CHAR) {
+                    continue;
+                }
+                let name = AddrTable::scoped_name(&scope, lbl);
+                let entry = asm.get_addrs().get_entry(&name)
+                            .unwrap_or_else(|e| panic!("{} in:\n{}", e, src));
+                assert_eq!(entry, addr, "label {} in:\n{}", name, src);
+            }
+        }
+    }
+
+    //this test checks assembling at a different origin
+    #[test]
+    fn test_origin() {
+        let opts = AsmOptions::new().with_origin(0x0600);
+        let code = "CLS\n_start:\nJMP _start";
+        let mut asm = Assembler::with_options(code, "eti.c8", &opts)
+                        .unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x00, 0xE0, 0x16, 0x02]);
+        assert_eq!(format!("{}", asm.get_listing()),
+                   "0600  00E0   CLS\n\
+                    0602         _start:\n\
+                    0602  1602   JMP _start\n");
+    }
+
+    //this test checks that programs past the end of memory are errors
+    #[test]
+    fn test_memory_overflow() {
+        let code = "$FF ".repeat(3600);
+        let mut asm = Assembler::new(&code, "big.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Overflow(oe)) => assert_eq!(oe.get_over(), 1),
+            _ => panic!("Expected an overflow error")
+        };
+
+        let opts = AsmOptions::new().with_origin(0x0FFC);
+        let mut asm = Assembler::with_options("CLS\nRET", "fit.c8", &opts)
+                        .unwrap();
+        assert!(asm.assemble().is_ok());
+    }
+
+    //this test checks that programs whose labels lie past
+    //64K are overflow errors instead of panics
+    #[test]
+    fn test_address_wrap() {
+        let code = "DS $FDFE\nCLS\nCLS\n_x: JMP _x\nDB #1, #2, #3";
+        let mut asm = Assembler::new(code, "wrap.c8").unwrap();
+        match asm.assemble_all() {
+            Err(errs) => match errs[0] {
+                AsmError::Overflow(_) => {},
+                _ => panic!("Expected an overflow error")
+            },
+            Ok(_) => panic!("Expected an overflow error")
+        };
+        assert!(asm.get_address() <= constants::MEM_SIZE);
+    }
+
+    //this test checks that reserving past the end of memory
+    //is an error instead of a panic
+    #[test]
+    fn test_reserve_overflow() {
+        let mut asm = Assembler::new("DS $FE00\nCLS", "ds.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Overflow(oe)) => assert_eq!(oe.get_over(), 0xF000),
+            _ => panic!("Expected an overflow error")
+        };
+
+        let mut asm = Assembler::new("DS $FFFF\nCLS", "ds.c8").unwrap();
+        match asm.assemble_all() {
+            Err(errs) => {
+                assert_eq!(errs.len(), 1);
+                match errs[0] {
+                    AsmError::Overflow(ref oe) => {
+                        assert_eq!(oe.get_over(), 0xF1FF);
+                    },
+                    _ => panic!("Expected an overflow error")
+                };
+            },
+            Ok(_) => panic!("Expected an overflow error")
+        };
+    }
+
+    //this test checks assembling binary literals of different lengths
+    #[test]
+    fn test_bin_lit_lengths() {
+        let code = "%101\n%1111000000001111\n_end:\nJMP _end";
+        let mut asm = Assembler::new(code, "bin.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x05, 0xF0, 0x0F, 0x12, 0x03]);
+    }
+
+    //this test checks reserving storage with DS
+    #[test]
+    fn test_define_storage() {
+        let code = "CLS\n_buf:\nDS #3\nDS $02\n_end:\nMOV I, _end";
+        let mut asm = Assembler::new(code, "ds.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x00, 0xE0, 0x00, 0x00, 0x00,
+                                      0x00, 0x00, 0xA2, 0x07]);
+        assert_eq!(asm.get_addrs().get_entry("_END").unwrap(), 0x0207);
+    }
+
+    //this test checks defining bytes and aligning code
+    #[test]
+    fn test_align() {
+        let code = "DB #1\nALIGN\n_a: CLS\nDB #1, $02, %00000011\n\
+                    ALIGN #4\n_b: RET\nALIGN #4";
+        let mut asm = Assembler::new(code, "align.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x01, 0x00, 0x00, 0xE0, 0x01, 0x02,
+                                      0x03, 0x00, 0x00, 0xEE, 0x00, 0x00]);
+        assert_eq!(asm.get_addrs().get_entry("_A").unwrap(), 0x0202);
+        assert_eq!(asm.get_addrs().get_entry("_B").unwrap(), 0x0208);
+    }
+
+    //this test checks assembling the SuperChip instructions
+    #[test]
+    fn test_schip_instrs() {
+        let code = "HIGH\nLOW\nSCR\nSCL\nEXIT\n$FF\n_end:\nJMP _end";
+        let mut asm = Assembler::new(code, "schip.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x00, 0xFF, 0x00, 0xFE, 0x00, 0xFB,
+                                      0x00, 0xFC, 0x00, 0xFD, 0xFF,
+                                      0x12, 0x0B]);
+    }
+
+    //this test checks loading a 16-bit address into I
+    #[test]
+    fn test_long_load() {
+        let code = "MOVL I, $FFFF\nMOVL I, _end\n_end:\nSKIP.EQ V0, #0\n\
+                    MOVL I, #4660\nRET";
+        let mut asm = Assembler::new(code, "movl.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0xF0, 0x00, 0xFF, 0xFF,
+                                      0xF0, 0x00, 0x02, 0x08,
+                                      0x30, 0x00,
+                                      0xF0, 0x00, 0x12, 0x34,
+                                      0x00, 0xEE]);
+        assert_eq!(asm.get_instr_count(), 5);
+        assert_eq!(asm.get_code_len(), 16);
+
+        //the skip passes over the whole long load
+        asm.check_calls();
+        assert_eq!(asm.get_warnings().len(), 1);
+
+        //only the I register can be loaded
+        let mut asm = Assembler::new("MOVL V1, $FFFF", "movl.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Argument(_ae)) => {},
+            _ => panic!("MOVL V1 was accepted")
+        }
+    }
+
+    //this test checks jumps and calls to constant addresses
+    #[test]
+    fn test_const_jumps() {
+        let code = "_start:\nCALL _start\nJMP $2A0\nCALL #512\nJPC $FFF";
+        let mut asm = Assembler::new(code, "jmp.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x22, 0x00, 0x12, 0xA0,
+                                      0x22, 0x00, 0xBF, 0xFF]);
+        assert!(asm.get_warnings().is_empty());
+
+        //addresses past the 12-bit field are errors, not truncated
+        for code in ["JMP $1000", "CALL $1000", "JPC #4096"] {
+            let mut asm = Assembler::new(code, "jmp.c8").unwrap();
+            match asm.assemble() {
+                Err(AsmError::Range(re)) => {
+                    assert!(format!("{}", re).starts_with("(1:"), "{}", re);
+                },
+                _ => panic!("{} was accepted", code)
+            };
+        }
+    }
+
+    //this test checks assembling source bytes that are not UTF-8
+    #[test]
+    fn test_from_bytes() {
+        let code = b"CLS ; caf\xE9\n;{ \xFF\xFE ;}\nRET";
+        let mut asm = Assembler::from_bytes(code, "bytes.c8",
+                                            &AsmOptions::new()).unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x00, 0xE0, 0x00, 0xEE]);
+
+        //high bytes are still errors outside of comments
+        let code = b"CLS\n\xE9\nRET";
+        let res = Assembler::from_bytes(code, "bytes.c8", &AsmOptions::new())
+                    .and_then(|mut asm| asm.assemble().map(|_| ()));
+        match res {
+            Err(AsmError::Lexer(_le)) => {},
+            _ => panic!("a high byte in code was accepted")
+        }
+    }
+
+    //this test checks that strings only hold ASCII, whether the
+    //source is UTF-8 or holds single high bytes
+    #[test]
+    fn test_from_bytes_strings() {
+        let codes: [&[u8]; 3] = [b"DB \"caf\xC3\xA9\"", b"DB \"caf\xE9\"",
+                                 b"DB \"caf\xE9\" ; caf\xE9"];
+        for code in codes.iter() {
+            let res = Assembler::from_bytes(code, "bytes.c8",
+                                            &AsmOptions::new())
+                        .and_then(|mut asm| asm.assemble().map(|_| ()));
+            match res {
+                Err(AsmError::Lexer(le)) => {
+                    assert!(format!("{}", le).starts_with("(1:8)"),
+                            "{}", le);
+                },
+                _ => panic!("a high byte in a string was accepted")
+            }
+        }
+
+        //while the ASCII in a string is written as is
+        let mut asm = Assembler::from_bytes(b"DB \"cafe\" ; caf\xE9",
+                                            "bytes.c8",
+                                            &AsmOptions::new()).unwrap();
+        assert_eq!(asm.assemble().unwrap(), b"cafe");
+    }
+
+    //this test checks that a string holding a character that is not
+    //ASCII is reported once, without lexing the rest of it as code
+    #[test]
+    fn test_non_ascii_string_errors() {
+        for code in ["DB \"\u{E9}\"", "DB \"caf\u{E9}, x\"\nCLS",
+                     "DB \"\u{E9}"] {
+            let mut asm = Assembler::new(code, "ascii.c8").unwrap();
+            let errs = asm.assemble_all().err().unwrap();
+            assert_eq!(errs.len(), 1, "{}", code);
+            match errs[0] {
+                AsmError::Lexer(_) => {},
+                ref e => panic!("unexpected error {}", e)
+            }
+        }
+    }
+
+    //this test checks assembling data lists with trailing comments
+    #[test]
+    fn test_data_comments() {
+        let code = "DB #1, #2 ; two bytes\n\
+                    DB #3, ; a list carried on\n\
+                    #4 ;{ block ;} ; and a comment\n\
+                    _end:\n\
+                    DW _end ; the address of the end";
+        let mut asm = Assembler::new(code, "data.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x01, 0x02, 0x03, 0x04, 0x02, 0x04]);
+        assert!(asm.get_warnings().is_empty());
+
+        //the same goes for other comment markers
+        let code = "DB #1, #2 // two bytes\nDB #3, // more\n#4\n_end:\n\
+                    DW _end//the end";
+        let opts = AsmOptions::new()
+                        .with_sigils(&Sigils::new().with_comment("//"));
+        let mut asm = Assembler::unnamed(code, &opts).unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x01, 0x02, 0x03, 0x04, 0x02, 0x04]);
+    }
+
+    //this test checks assembling without preprocessing
+    #[test]
+    fn test_no_preprocess() {
+        //code with only backward references assembles the same
+        let opts = AsmOptions::new().with_preprocess(false);
+        let mut asm = Assembler::unnamed(CODE, &opts).unwrap();
+        asm.assemble().unwrap();
+        let mut fresh = Assembler::new(CODE, "prep.c8").unwrap();
+        fresh.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), fresh.get_bytes());
+        assert_eq!(asm.get_addrs().get_entry("_loop").unwrap(), 0x020A);
+
+        //local labels and aliases are defined as they are found
+        let code = "_a:\n.loop:\nC REG V3\nADD C, #1\nJMP .loop";
+        let mut asm = Assembler::unnamed(code, &opts).unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x73, 0x01, 0x12, 0x00]);
+
+        //but forward references are errors
+        let mut asm = Assembler::unnamed("JMP _end\n_end:", &opts).unwrap();
+        match asm.assemble() {
+            Err(AsmError::Address(_ae)) => {},
+            _ => panic!("a forward reference was resolved")
+        }
+    }
+
+    //this test checks describing errors and warnings for tools
+    #[test]
+    fn test_diagnostics() {
+        let code = "MOV V1, V1\nFOO V2\nDRAW I, V1, #1";
+        let mut asm = Assembler::new(code, "diag.c8").unwrap();
+        let errs = asm.assemble_all().err().unwrap();
+        let mut diags: Vec<Diagnostic> = errs.iter()
+                                            .map(Diagnostic::from_error)
+                                            .collect();
+        diags.extend(asm.get_warnings().iter().map(Diagnostic::from_warning));
+        let json = serde_json::to_string(&diags).unwrap();
+        assert!(json.starts_with("[{\"line\":2,\"col\":1,\
+                                  \"severity\":\"error\",\
+                                  \"message\":\"unknown instruction FOO\"},\
+                                  {\"line\":3,\"col\":6,"), "{}", json);
+        assert!(json.ends_with("{\"line\":1,\"col\":1,\
+                                \"severity\":\"warning\",\
+                                \"message\":\"moving a register to itself \
+                                has no effect\"}]"), "{}", json);
+
+        //errors with no position have no line or column
+        let diag = Diagnostic::from_error(&AsmError::Overflow(
+                                            OverflowError::new(2)));
+        assert_eq!((diag.get_line(), diag.get_column()), (None, None));
+        assert_eq!(diag.get_severity(), "error");
+        assert!(!diag.get_message().is_empty());
+    }
+
+    //this test checks reusing an assembler for another program
+    #[test]
+    fn test_reset() {
+        let second = "_start:\nMOV V1, V1\nDRAW V0, V1, #5\nJMP _start";
+        let opts = AsmOptions::new().with_origin(0x300);
+        let mut asm = Assembler::unnamed(CODE, &opts).unwrap();
+        asm.assemble().unwrap();
+        asm.reset(second, "reset.c8").unwrap();
+        asm.assemble().unwrap();
+
+        //the result matches a fresh assembler with the same options
+        let mut fresh = Assembler::unnamed(second, &opts).unwrap();
+        fresh.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), fresh.get_bytes());
+        assert_eq!(asm.get_bytes(), &[0x81, 0x10, 0xD0, 0x15, 0x13, 0x00]);
+        assert_eq!(asm.get_warnings().len(), 1);
+        assert_eq!(asm.get_instr_count(), 3);
+
+        //and going back gives the first program again
+        asm.reset(CODE, "reset.c8").unwrap();
+        asm.assemble().unwrap();
+        let mut fresh = Assembler::unnamed(CODE, &opts).unwrap();
+        fresh.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), fresh.get_bytes());
+        assert!(asm.get_warnings().is_empty());
+    }
+
+    //this test checks recording code and data sections
+    #[test]
+    fn test_sections() {
+        let code = ".code\n_start:\nCLS\nJMP _start\n\
+                    .data\n_spr:\nDB $FF, $81, $FF\n\
+                    .code\n_sub:\nRET";
+        let mut asm = Assembler::new(code, "sect.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x00, 0xE0, 0x12, 0x00,
+                                      0xFF, 0x81, 0xFF, 0x00, 0xEE]);
+        assert!(asm.get_warnings().is_empty());
+        let sections: Vec<_> = asm.get_sections().iter().collect();
+        assert_eq!(sections, vec![(0x200, 0x204, Section::Code),
+                                  (0x204, 0x207, Section::Data),
+                                  (0x207, 0x209, Section::Code)]);
+        assert_eq!(asm.get_addrs().get_entry("_sub").unwrap(), 0x0207);
+
+        //local labels with other names are unaffected
+        let code = "_a:\n.datum:\nJMP .datum";
+        let mut asm = Assembler::new(code, "sect.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0x12, 0x00]);
+        assert!(asm.get_sections().is_empty());
+    }
+
+    //this test checks that FONT is another name for SCH
+    #[test]
+    fn test_font() {
+        let mut font = Assembler::new("FONT V0\nFONT VA", "font.c8").unwrap();
+        font.assemble().unwrap();
+        let mut sch = Assembler::new("SCH V0\nSCH VA", "font.c8").unwrap();
+        sch.assemble().unwrap();
+        assert_eq!(font.get_bytes(), sch.get_bytes());
+        assert_eq!(font.get_bytes(), &[0xF0, 0x29, 0xFA, 0x29]);
+
+        //errors name the mnemonic that was written
+        let mut asm = Assembler::new("FONT I", "font.c8").unwrap();
+        let msg = format!("{}", asm.assemble().err().unwrap());
+        assert!(msg.contains("bad argument I for FONT"), "{}", msg);
+    }
+
+    //this test checks assembling the large font character instruction
+    #[test]
+    fn test_lch() {
+        let code = "LCH V2\n_end:\nJMP _end";
+        let mut asm = Assembler::new(code, "lch.c8").unwrap();
+        asm.assemble().unwrap();
+        assert_eq!(asm.get_bytes(), &[0xF2, 0x30, 0x12, 0x02]);
+        assert_eq!(asm.get_addrs().get_entry("_end").unwrap(), 0x0202);
+
+        //the I register cannot be used
+        let mut asm = Assembler::new("LCH I", "lch.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Argument(_ae)) => {},
+            _ => panic!("LCH I was accepted")
+        }
+    }
+
+    //this test checks that an unknown skip condition is an error
+    #[test]
+    fn test_bad_skip_cond() {
+        let mut asm = Assembler::new("SKIP.KEY V0", "test.c8").unwrap();
+        match asm.assemble() {
+            Err(AsmError::Condition(ce)) => {
+                assert!(format!("{}", ce)
+                        .contains("unknown skip condition KEY"));
+            },
+            _ => panic!("Expected a condition error")
         };
     }
 }
@@ -0,0 +1,153 @@
+/*
+ * decode_opcode.rs
+ * Defines a function that decodes an opcode into an instruction
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statement
+use super::Instr;
+
+/// Decodes a two-byte opcode into the instruction it encodes
+///
+/// The base Chip-8 and SuperChip instructions can be decoded. The
+/// `F000` long load is four bytes long, so it is treated like any
+/// other unknown opcode.
+///
+/// # Arguments
+///
+/// * `hi` - The first (most significant) byte of the opcode
+/// * `lo` - The second (least significant) byte of the opcode
+///
+/// # Returns
+///
+/// The decoded instruction, or `None` if the bytes
+/// are not a known opcode
+pub fn decode_opcode(hi: u8, lo: u8) -> Option<Instr> {
+    //get the fields of the opcode
+    let op = u16::from_be_bytes([hi, lo]);
+    let x = hi & 0xF;
+    let y = lo >> 4;
+    let n = lo & 0xF;
+    let nnn = op & 0xFFF;
+
+    //and decode it
+    return match (hi >> 4, n) {
+        (0x0, _) if op == 0x00E0 => Some(Instr::Cls),
+        (0x0, _) if op == 0x00EE => Some(Instr::Ret),
+        (0x0, _) if op == 0x00FB => Some(Instr::Scr),
+        (0x0, _) if op == 0x00FC => Some(Instr::Scl),
+        (0x0, _) if op == 0x00FD => Some(Instr::Exit),
+        (0x0, _) if op == 0x00FE => Some(Instr::Low),
+        (0x0, _) if op == 0x00FF => Some(Instr::High),
+        (0x1, _) => Some(Instr::Jmp(nnn)),
+        (0x2, _) => Some(Instr::Call(nnn)),
+        (0x3, _) => Some(Instr::SkipEqConst(x, lo)),
+        (0x4, _) => Some(Instr::SkipNeConst(x, lo)),
+        (0x5, 0x0) => Some(Instr::SkipEqReg(x, y)),
+        (0x6, _) => Some(Instr::MovConst(x, lo)),
+        (0x7, _) => Some(Instr::AddConst(x, lo)),
+        (0x8, 0x0) => Some(Instr::MovReg(x, y)),
+        (0x8, 0x1) => Some(Instr::Or(x, y)),
+        (0x8, 0x2) => Some(Instr::And(x, y)),
+        (0x8, 0x3) => Some(Instr::Xor(x, y)),
+        (0x8, 0x4) => Some(Instr::AddReg(x, y)),
+        (0x8, 0x5) => Some(Instr::Sub(x, y)),
+        (0x8, 0x6) if x == y => Some(Instr::Shr(x)),
+        (0x8, 0x7) => Some(Instr::Subn(x, y)),
+        (0x8, 0xE) if x == y => Some(Instr::Shl(x)),
+        (0x9, 0x0) => Some(Instr::SkipNeReg(x, y)),
+        (0xA, _) => Some(Instr::MovI(nnn)),
+        (0xB, _) => Some(Instr::Jpc(nnn)),
+        (0xC, _) => Some(Instr::Rand(x, lo)),
+        (0xD, _) => Some(Instr::Draw(x, y, n)),
+        (0xE, _) if lo == 0x9E => Some(Instr::SkipKeyDown(x)),
+        (0xE, _) if lo == 0xA1 => Some(Instr::SkipKeyUp(x)),
+        (0xF, _) => match lo {
+            0x07 => Some(Instr::Gdl(x)),
+            0x0A => Some(Instr::Key(x)),
+            0x15 => Some(Instr::Sdl(x)),
+            0x18 => Some(Instr::Snd(x)),
+            0x1E => Some(Instr::AddI(x)),
+            0x29 => Some(Instr::Sch(x)),
+            0x30 => Some(Instr::Lch(x)),
+            0x33 => Some(Instr::Bcd(x)),
+            0x55 => Some(Instr::Rdp(x)),
+            0x65 => Some(Instr::Rld(x)),
+            _ => None
+        },
+        _ => None
+    };
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the decode_opcode function
+    use super::*;
+    use super::super::Assembler;
+
+    //this test checks that every decoded opcode
+    //reassembles into the same bytes
+    #[test]
+    fn test_round_trip() {
+        //decode the opcodes that start with each byte
+        for hi in 0..=0xFFu8 {
+            let mut src = String::new();
+            let mut ops = Vec::new();
+            for lo in 0..=0xFFu8 {
+                if let Some(instr) = decode_opcode(hi, lo) {
+                    src.push_str(&format!("{}\n", instr));
+                    ops.extend_from_slice(&[hi, lo]);
+                }
+            }
+
+            //and check that they assemble back into themselves
+            let mut asm = Assembler::new(&src, "dec.c8").unwrap();
+            asm.assemble().unwrap();
+            assert_eq!(asm.get_bytes(), &ops[..], "{:02X}xx", hi);
+        }
+    }
+
+    //this test checks decoding one opcode from each group
+    #[test]
+    fn test_decode_opcode() {
+        assert_eq!(decode_opcode(0x8A, 0xB4), Some(Instr::AddReg(0xA, 0xB)));
+        assert_eq!(decode_opcode(0xF5, 0x1E), Some(Instr::AddI(5)));
+        assert_eq!(decode_opcode(0x00, 0xFD), Some(Instr::Exit));
+        for (op, known) in [(0x00E0, true), (0x00EE, true), (0x00FF, true),
+                            (0x00FA, false), (0x1234, true), (0x2456, true),
+                            (0x3A12, true), (0x4B34, true), (0x5120, true),
+                            (0x5121, false), (0x6C56, true), (0x7D78, true),
+                            (0x8120, true), (0x8226, true), (0x8126, false),
+                            (0x833E, true), (0x8128, false), (0x9120, true),
+                            (0xA123, true), (0xB456, true), (0xC1FF, true),
+                            (0xD125, true), (0xE19E, true), (0xE1A1, true),
+                            (0xE1A2, false), (0xF107, true), (0xF10A, true),
+                            (0xF115, true), (0xF118, true), (0xF11E, true),
+                            (0xF129, true), (0xF130, true), (0xF133, true),
+                            (0xF155, true), (0xF165, true), (0xF000, false),
+                            (0xF131, false)] {
+            let [hi, lo] = u16::to_be_bytes(op);
+            assert_eq!(decode_opcode(hi, lo).is_some(), known,
+                       "{:04X}", op);
+        }
+    }
+}
+
+//end of file
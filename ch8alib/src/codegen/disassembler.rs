@@ -0,0 +1,247 @@
+/*
+ * disassembler.rs
+ * Defines a struct that disassembles Chip-8 binaries
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use std::collections::BTreeSet;
+use super::super::util::constants;
+use super::Instr;
+use super::decode_opcode;
+
+/// Disassembles Chip-8 binaries into source code
+/// that the `Assembler` accepts
+pub struct Disassembler {
+    /// The bytes being disassembled
+    bytes: Vec<u8>,
+
+    /// The address the first byte is loaded at
+    origin: u16
+}
+
+//implementation
+impl Disassembler {
+    /// Constructs a new `Disassembler` instance
+    ///
+    /// # Argument
+    ///
+    /// * `new_bytes` - The bytes of the binary to disassemble
+    ///
+    /// # Returns
+    ///
+    /// A new `Disassembler` instance for the given bytes
+    pub fn new(new_bytes: &[u8]) -> Self {
+        return Disassembler {
+            bytes: new_bytes.to_vec(),
+            origin: constants::MEM_START
+        };
+    }
+
+    /// Disassembles the binary
+    ///
+    /// Jump, call, and `MOV I` targets inside the binary are given
+    /// labels named `_lbl_0xNNN`. Words that are not instructions,
+    /// or that refer to addresses that cannot be labelled, are
+    /// written as byte literals so that the output reassembles
+    /// into the same bytes.
+    ///
+    /// # Returns
+    ///
+    /// The source code for the binary, one statement per line
+    pub fn disassemble(&self) -> String {
+        //find the targets that need labels
+        let labels = self.find_labels();
+
+        //create the return value
+        let mut ret = String::new();
+
+        //and disassemble each word
+        for (i, chunk) in self.bytes.chunks(2).enumerate() {
+            //get the address of the word
+            let addr = self.origin + (i as u16 * 2);
+
+            //write a label definition if one is needed
+            if labels.contains(&addr) {
+                ret.push_str(&format!("_lbl_0x{:03X}:\n", addr));
+            }
+
+            //handle a trailing byte
+            if chunk.len() == 1 {
+                ret.push_str(&format!("    ${:02X}\n", chunk[0]));
+                continue;
+            }
+
+            //and write the statement for the word
+            let op = ((chunk[0] as u16) << 8) | (chunk[1] as u16);
+            let stmt = match self.decode(op, &labels) {
+                Some(s) => s,
+                None => format!("${:02X} ${:02X}", chunk[0], chunk[1])
+            };
+            ret.push_str(&format!("    {}\n", stmt));
+        }
+
+        //and return the source code
+        return ret;
+    }
+
+    /// Finds the addresses that are referred to by jumps,
+    /// calls, and `MOV I` instructions and can be labelled
+    ///
+    /// # Returns
+    ///
+    /// The set of addresses that need label definitions
+    fn find_labels(&self) -> BTreeSet<u16> {
+        let mut ret = BTreeSet::new();
+        for chunk in self.bytes.chunks_exact(2) {
+            match decode_opcode(chunk[0], chunk[1]) {
+                Some(Instr::Jmp(addr)) | Some(Instr::Call(addr)) |
+                Some(Instr::MovI(addr)) if self.is_labelled(addr) => {
+                    ret.insert(addr);
+                },
+                _ => {}
+            };
+        }
+        return ret;
+    }
+
+    /// Determines whether an address can be given a label
+    ///
+    /// # Argument
+    ///
+    /// * `addr` - The address to check
+    ///
+    /// # Returns
+    ///
+    /// Whether the address starts a word inside the binary
+    fn is_labelled(&self, addr: u16) -> bool {
+        return (addr >= self.origin) &&
+                ((addr - self.origin) as usize) < self.bytes.len() &&
+                (addr - self.origin).is_multiple_of(2);
+    }
+
+    /// Decodes a single opcode into a statement
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - The opcode to decode
+    /// * `labels` - The addresses that have label definitions
+    ///
+    /// # Returns
+    ///
+    /// The statement for the opcode, or `None` if the opcode
+    /// cannot be written as an instruction
+    fn decode(&self, op: u16, labels: &BTreeSet<u16>) -> Option<String> {
+        //get the label for the address field
+        let nnn = op & 0xFFF;
+        let lbl = if labels.contains(&nnn) {
+            Some(format!("_lbl_0x{:03X}", nnn))
+        } else {
+            None
+        };
+
+        //and generate the statement, naming its address if possible
+        let [hi, lo] = op.to_be_bytes();
+        return match (decode_opcode(hi, lo)?, lbl) {
+            (Instr::Jmp(_), lbl) => lbl.map(|l| format!("JMP {}", l)),
+            (Instr::Call(_), lbl) => lbl.map(|l| format!("CALL {}", l)),
+            (Instr::MovI(_), Some(l)) => Some(format!("MOV I, {}", l)),
+            (instr, _) => Some(instr.to_string())
+        };
+    }
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the Disassembler struct
+    use super::*;
+    use super::super::Assembler;
+
+    //this test checks disassembling a binary
+    #[test]
+    fn test_disassemble() {
+        let bytes = [0x00, 0xE0, 0x12, 0x00, 0xA2, 0x08, 0x13, 0x00, 0xFF];
+        let dis = Disassembler::new(&bytes);
+        assert_eq!(dis.disassemble(),
+                   "_lbl_0x200:\n    \
+                    CLS\n    \
+                    JMP _lbl_0x200\n    \
+                    MOV I, _lbl_0x208\n    \
+                    $13 $00\n\
+                    _lbl_0x208:\n    \
+                    $FF\n");
+    }
+
+    //this test checks that disassembled code reassembles
+    //into the same bytes
+    #[test]
+    fn test_round_trip() {
+        let code = "CLS
+                    MOV V0, #0
+                    MOV V1, $FF
+                    MOV I, _sprite
+                    _loop:
+                    DRAW V0, V1, #5
+                    SKIP.EQ V0, V1
+                    SKIP.NE V2, #3
+                    SKIP.KD V3
+                    SKIP.KU V4
+                    ADD V0, #1
+                    ADD V0, V1
+                    ADD I, V2
+                    OR V1, V2
+                    AND V1, V2
+                    XOR V1, V2
+                    SUB V1, V2
+                    SUBN V1, V2
+                    SHR V3
+                    SHL V3
+                    RAND V5, $0F
+                    GDL V6
+                    KEY V7
+                    SDL V8
+                    SND V9
+                    SCH VA
+                    BCD VB
+                    RDP VC
+                    RLD VD
+                    JPC $300
+                    CALL _sub
+                    JMP _loop
+                    _sub:
+                    RET
+                    HIGH
+                    LOW
+                    SCR
+                    SCL
+                    EXIT
+                    _sprite:
+                    $F0 $90 $F0";
+        let mut asm = Assembler::new(code, "rt1.c8").unwrap();
+        asm.assemble().unwrap();
+        let bytes = asm.get_bytes().to_vec();
+        let src = Disassembler::new(&bytes).disassemble();
+        let mut asm2 = Assembler::new(&src, "rt2.c8").unwrap();
+        asm2.assemble().unwrap();
+        assert_eq!(asm2.get_bytes(), bytes.as_slice());
+    }
+}
+
+//end of file
@@ -0,0 +1,270 @@
+/*
+ * encode_instr.rs
+ * Defines a function that encodes a single instruction
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statement
+use super::Instr;
+
+/// Encodes a single instruction into its opcode
+///
+/// This is the encoding the `Assembler` uses for each instruction,
+/// so it can be used to check the encoding of an instruction
+/// on its own.
+///
+/// # Argument
+///
+/// * `instr` - The instruction to encode
+///
+/// # Returns
+///
+/// The bytes of the opcode (two, or four for `MOVL`)
+pub fn encode_instr(instr: &Instr) -> Vec<u8> {
+    //get the opcode of the instruction
+    let op: u16 = match *instr {
+        Instr::Cls => 0x00E0,
+        Instr::Ret => 0x00EE,
+        Instr::Scr => 0x00FB,
+        Instr::Scl => 0x00FC,
+        Instr::Exit => 0x00FD,
+        Instr::Low => 0x00FE,
+        Instr::High => 0x00FF,
+        Instr::Jmp(a) => 0x1000 | (a & 0x0FFF),
+        Instr::Call(a) => 0x2000 | (a & 0x0FFF),
+        Instr::SkipEqConst(x, nn) => 0x3000 | x_bits(x) | (nn as u16),
+        Instr::SkipNeConst(x, nn) => 0x4000 | x_bits(x) | (nn as u16),
+        Instr::SkipEqReg(x, y) => 0x5000 | x_bits(x) | y_bits(y),
+        Instr::MovConst(x, nn) => 0x6000 | x_bits(x) | (nn as u16),
+        Instr::AddConst(x, nn) => 0x7000 | x_bits(x) | (nn as u16),
+        Instr::MovReg(x, y) => 0x8000 | x_bits(x) | y_bits(y),
+        Instr::Or(x, y) => 0x8001 | x_bits(x) | y_bits(y),
+        Instr::And(x, y) => 0x8002 | x_bits(x) | y_bits(y),
+        Instr::Xor(x, y) => 0x8003 | x_bits(x) | y_bits(y),
+        Instr::AddReg(x, y) => 0x8004 | x_bits(x) | y_bits(y),
+        Instr::Sub(x, y) => 0x8005 | x_bits(x) | y_bits(y),
+        Instr::Shr(x) => 0x8006 | x_bits(x) | y_bits(x),
+        Instr::Subn(x, y) => 0x8007 | x_bits(x) | y_bits(y),
+        Instr::Shl(x) => 0x800E | x_bits(x) | y_bits(x),
+        Instr::SkipNeReg(x, y) => 0x9000 | x_bits(x) | y_bits(y),
+        Instr::MovI(a) => 0xA000 | (a & 0x0FFF),
+        Instr::Jpc(a) => 0xB000 | (a & 0x0FFF),
+        Instr::Rand(x, nn) => 0xC000 | x_bits(x) | (nn as u16),
+        Instr::Draw(x, y, n) => {
+            0xD000 | x_bits(x) | y_bits(y) | ((n & 0x0F) as u16)
+        },
+        Instr::SkipKeyDown(x) => 0xE09E | x_bits(x),
+        Instr::SkipKeyUp(x) => 0xE0A1 | x_bits(x),
+        Instr::Movl(_) => 0xF000,
+        Instr::Gdl(x) => 0xF007 | x_bits(x),
+        Instr::Key(x) => 0xF00A | x_bits(x),
+        Instr::Sdl(x) => 0xF015 | x_bits(x),
+        Instr::Snd(x) => 0xF018 | x_bits(x),
+        Instr::AddI(x) => 0xF01E | x_bits(x),
+        Instr::Sch(x) => 0xF029 | x_bits(x),
+        Instr::Lch(x) => 0xF030 | x_bits(x),
+        Instr::Bcd(x) => 0xF033 | x_bits(x),
+        Instr::Rdp(x) => 0xF055 | x_bits(x),
+        Instr::Rld(x) => 0xF065 | x_bits(x)
+    };
+
+    //and return its bytes, followed by the
+    //address word of a long load
+    let mut ret = op.to_be_bytes().to_vec();
+    if let Instr::Movl(addr) = *instr {
+        ret.extend_from_slice(&addr.to_be_bytes());
+    }
+    return ret;
+}
+
+/// Places a register index in the X field (bits 8-11) of an opcode
+///
+/// # Argument
+///
+/// * `reg` - The index of the register
+///
+/// # Returns
+///
+/// The index shifted into the X field
+fn x_bits(reg: u8) -> u16 {
+    return ((reg & 0x0F) as u16) << 8;
+}
+
+/// Places a register index in the Y field (bits 4-7) of an opcode
+///
+/// # Argument
+///
+/// * `reg` - The index of the register
+///
+/// # Returns
+///
+/// The index shifted into the Y field
+fn y_bits(reg: u8) -> u16 {
+    return ((reg & 0x0F) as u16) << 4;
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the encode_instr function
+    use super::*;
+    use super::super::Assembler;
+    use super::super::AsmOptions;
+    use super::super::decode_opcode;
+
+    //the documented opcode of every instruction form,
+    //with operands that fill each field differently
+    const OPCODES: [(&str, Instr, &[u8]); 48] = [
+        ("CLS", Instr::Cls,
+            &[0x00, 0xE0]),
+        ("RET", Instr::Ret,
+            &[0x00, 0xEE]),
+        ("SCR", Instr::Scr,
+            &[0x00, 0xFB]),
+        ("SCL", Instr::Scl,
+            &[0x00, 0xFC]),
+        ("EXIT", Instr::Exit,
+            &[0x00, 0xFD]),
+        ("LOW", Instr::Low,
+            &[0x00, 0xFE]),
+        ("HIGH", Instr::High,
+            &[0x00, 0xFF]),
+        ("JMP $ABC", Instr::Jmp(0xABC),
+            &[0x1A, 0xBC]),
+        ("CALL $123", Instr::Call(0x123),
+            &[0x21, 0x23]),
+        ("SKIP.EQ V3, #66", Instr::SkipEqConst(3, 66),
+            &[0x33, 0x42]),
+        ("SKIP.NE VC, $FF", Instr::SkipNeConst(0xC, 0xFF),
+            &[0x4C, 0xFF]),
+        ("SKIP.EQ V1, V2", Instr::SkipEqReg(1, 2),
+            &[0x51, 0x20]),
+        ("MOV V4, $7A", Instr::MovConst(4, 0x7A),
+            &[0x64, 0x7A]),
+        ("ADD V2, #16", Instr::AddConst(2, 16),
+            &[0x72, 0x10]),
+        ("MOV V5, V9", Instr::MovReg(5, 9),
+            &[0x85, 0x90]),
+        ("OR V1, V2", Instr::Or(1, 2),
+            &[0x81, 0x21]),
+        ("AND V3, V4", Instr::And(3, 4),
+            &[0x83, 0x42]),
+        ("XOR V5, V6", Instr::Xor(5, 6),
+            &[0x85, 0x63]),
+        ("ADD V3, V4", Instr::AddReg(3, 4),
+            &[0x83, 0x44]),
+        ("SUB V7, V8", Instr::Sub(7, 8),
+            &[0x87, 0x85]),
+        ("SHR V9", Instr::Shr(9),
+            &[0x89, 0x96]),
+        ("SUBN VA, VB", Instr::Subn(0xA, 0xB),
+            &[0x8A, 0xB7]),
+        ("SHL VC", Instr::Shl(0xC),
+            &[0x8C, 0xCE]),
+        ("SKIP.NE VE, VD", Instr::SkipNeReg(0xE, 0xD),
+            &[0x9E, 0xD0]),
+        ("MOV I, $ABC", Instr::MovI(0xABC),
+            &[0xAA, 0xBC]),
+        ("MOV I, $FFF", Instr::MovI(0xFFF),
+            &[0xAF, 0xFF]),
+        ("JPC $3FF", Instr::Jpc(0x3FF),
+            &[0xB3, 0xFF]),
+        ("RAND VD, $0F", Instr::Rand(0xD, 0x0F),
+            &[0xCD, 0x0F]),
+        ("DRAW VA, VB, #15", Instr::Draw(0xA, 0xB, 15),
+            &[0xDA, 0xBF]),
+        ("DRAW V0, V1, #0", Instr::Draw(0, 1, 0),
+            &[0xD0, 0x10]),
+        ("SKIP.KD V7", Instr::SkipKeyDown(7),
+            &[0xE7, 0x9E]),
+        ("SKIP.KU VF", Instr::SkipKeyUp(0xF),
+            &[0xEF, 0xA1]),
+        ("GDL V1", Instr::Gdl(1),
+            &[0xF1, 0x07]),
+        ("MOV V6, DELAY", Instr::Gdl(6),
+            &[0xF6, 0x07]),
+        ("KEY V2", Instr::Key(2),
+            &[0xF2, 0x0A]),
+        ("SDL V3", Instr::Sdl(3),
+            &[0xF3, 0x15]),
+        ("MOV DELAY, V8", Instr::Sdl(8),
+            &[0xF8, 0x15]),
+        ("SND V4", Instr::Snd(4),
+            &[0xF4, 0x18]),
+        ("MOV SOUND, VB", Instr::Snd(0xB),
+            &[0xFB, 0x18]),
+        ("ADD I, V5", Instr::AddI(5),
+            &[0xF5, 0x1E]),
+        ("SCH V5", Instr::Sch(5),
+            &[0xF5, 0x29]),
+        ("FONT V6", Instr::Sch(6),
+            &[0xF6, 0x29]),
+        ("LCH V7", Instr::Lch(7),
+            &[0xF7, 0x30]),
+        ("BCD V8", Instr::Bcd(8),
+            &[0xF8, 0x33]),
+        ("RDP V9", Instr::Rdp(9),
+            &[0xF9, 0x55]),
+        ("RLD VA", Instr::Rld(0xA),
+            &[0xFA, 0x65]),
+        ("MOVL I, $ABCD", Instr::Movl(0xABCD),
+            &[0xF0, 0x00, 0xAB, 0xCD]),
+        ("MOVL I, $0200", Instr::Movl(0x0200),
+            &[0xF0, 0x00, 0x02, 0x00])
+    ];
+
+    //assembles a single statement into its bytes
+    fn assemble(stmt: &str) -> Vec<u8> {
+        let mut asm = Assembler::unnamed(stmt, &AsmOptions::new()).unwrap();
+        return asm.assemble().unwrap().to_vec();
+    }
+
+    //this test checks every instruction form against its opcode
+    #[test]
+    fn test_opcodes() {
+        for (stmt, instr, op) in OPCODES.iter() {
+            assert_eq!(&encode_instr(instr)[..], *op, "{}", stmt);
+        }
+    }
+
+    //this test checks that the assembler generates the same opcodes
+    #[test]
+    fn test_assembled_opcodes() {
+        for (stmt, _, op) in OPCODES.iter() {
+            assert_eq!(&assemble(stmt)[..], *op, "{}", stmt);
+        }
+    }
+
+    //this test checks that formatted instructions reassemble
+    //and that two-byte opcodes decode back to their instruction
+    #[test]
+    fn test_formatted_opcodes() {
+        for (stmt, instr, op) in OPCODES.iter() {
+            let text = instr.to_string();
+            assert_eq!(&assemble(&text)[..], *op,
+                       "{} formatted as {}", stmt, text);
+            if op.len() == 2 {
+                assert_eq!(decode_opcode(op[0], op[1]), Some(*instr),
+                           "{}", stmt);
+            }
+        }
+    }
+}
+
+//end of file
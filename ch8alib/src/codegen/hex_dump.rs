@@ -0,0 +1,91 @@
+/*
+ * hex_dump.rs
+ * Defines a function that formats bytes as a hex dump
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+/// The number of bytes shown on each line of a hex dump
+const LINE_LEN: usize = 16;
+
+/// Formats assembled bytes as a classic hex dump
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to format
+/// * `origin` - The address the first byte is loaded at
+///
+/// # Returns
+///
+/// One line per 16 bytes, showing the address of the line,
+/// the bytes in hex, and the printable bytes as ASCII
+pub fn hex_dump(bytes: &[u8], origin: u16) -> String {
+    //create the return value
+    let mut ret = String::new();
+
+    //format each line
+    for (i, chunk) in bytes.chunks(LINE_LEN).enumerate() {
+        //write the address
+        let addr = origin.wrapping_add((i * LINE_LEN) as u16);
+        ret.push_str(&format!("{:04X} ", addr));
+
+        //write the bytes in hex
+        for b in chunk {
+            ret.push_str(&format!(" {:02X}", b));
+        }
+
+        //pad out a short final line
+        for _ in chunk.len()..LINE_LEN {
+            ret.push_str("   ");
+        }
+
+        //and write the ASCII gutter
+        let text: String = chunk.iter().map(|b| {
+            if b.is_ascii_graphic() || (*b == b' ') {
+                *b as char
+            } else {
+                '.'
+            }
+        }).collect();
+        ret.push_str(&format!("  |{}|\n", text));
+    }
+
+    //and return the dump
+    return ret;
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the hex_dump function
+    use super::*;
+
+    //this test checks formatting a hex dump
+    #[test]
+    fn test_hex_dump() {
+        let mut bytes: Vec<u8> = b"Hello, Chip-8!\x00\xE0".to_vec();
+        bytes.extend_from_slice(&[0x41, 0x42]);
+        assert_eq!(hex_dump(&bytes, 0x0200),
+                   "0200  48 65 6C 6C 6F 2C 20 43 68 69 70 2D 38 21 00 E0  \
+                    |Hello, Chip-8!..|\n\
+                    0210  41 42                                            \
+                    |AB|\n");
+    }
+}
+
+//end of file
@@ -0,0 +1,92 @@
+/*
+ * ihex.rs
+ * Defines a function that formats bytes as Intel HEX records
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+/// The number of data bytes in each Intel HEX record
+const RECORD_LEN: usize = 16;
+
+/// Formats assembled bytes as Intel HEX records
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to format
+/// * `origin` - The address the first byte is loaded at
+///
+/// # Returns
+///
+/// The data records for the bytes followed by an end-of-file
+/// record, one record per line
+pub fn to_ihex(bytes: &[u8], origin: u16) -> String {
+    //create the return value
+    let mut ret = String::new();
+
+    //write a data record for each chunk of bytes
+    for (i, chunk) in bytes.chunks(RECORD_LEN).enumerate() {
+        //get the address of the record
+        let addr = origin.wrapping_add((i * RECORD_LEN) as u16);
+
+        //write the record header
+        ret.push_str(&format!(":{:02X}{:04X}00", chunk.len(), addr));
+
+        //write the data and sum up the record
+        let mut sum = (chunk.len() as u8)
+                        .wrapping_add((addr >> 8) as u8)
+                        .wrapping_add(addr as u8);
+        for b in chunk {
+            ret.push_str(&format!("{:02X}", b));
+            sum = sum.wrapping_add(*b);
+        }
+
+        //and write the checksum
+        ret.push_str(&format!("{:02X}\n", sum.wrapping_neg()));
+    }
+
+    //write the end-of-file record
+    ret.push_str(":00000001FF\n");
+
+    //and return the records
+    return ret;
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the to_ihex function
+    use super::*;
+
+    //this test checks formatting bytes as Intel HEX
+    #[test]
+    fn test_ihex_records() {
+        let bytes: Vec<u8> = (0..18).collect();
+        assert_eq!(to_ihex(&bytes, 0x0200),
+                   ":10020000000102030405060708090A0B0C0D0E0F76\n\
+                    :020210001011CB\n\
+                    :00000001FF\n");
+    }
+
+    //this test checks formatting no bytes
+    #[test]
+    fn test_ihex_empty() {
+        assert_eq!(to_ihex(&[], 0x0200), ":00000001FF\n");
+    }
+}
+
+//end of file
@@ -0,0 +1,252 @@
+/*
+ * instr.rs
+ * Defines an enum that represents a single Chip-8 instruction
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use std::fmt;
+
+/// A single Chip-8 instruction, with its operands
+///
+/// Registers are given by their index (`0` for `V0` through
+/// `15` for `VF`), and addresses and constants by their value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Instr {
+    /// `CLS`, which clears the screen
+    Cls,
+
+    /// `RET`, which returns from a subroutine
+    Ret,
+
+    /// `SCR`, which scrolls the screen right (SuperChip)
+    Scr,
+
+    /// `SCL`, which scrolls the screen left (SuperChip)
+    Scl,
+
+    /// `EXIT`, which stops the interpreter (SuperChip)
+    Exit,
+
+    /// `LOW`, which switches to low resolution (SuperChip)
+    Low,
+
+    /// `HIGH`, which switches to high resolution (SuperChip)
+    High,
+
+    /// `JMP nnn`
+    Jmp(u16),
+
+    /// `CALL nnn`
+    Call(u16),
+
+    /// `SKIP.EQ Vx, nn`
+    SkipEqConst(u8, u8),
+
+    /// `SKIP.NE Vx, nn`
+    SkipNeConst(u8, u8),
+
+    /// `SKIP.EQ Vx, Vy`
+    SkipEqReg(u8, u8),
+
+    /// `SKIP.NE Vx, Vy`
+    SkipNeReg(u8, u8),
+
+    /// `SKIP.KD Vx`
+    SkipKeyDown(u8),
+
+    /// `SKIP.KU Vx`
+    SkipKeyUp(u8),
+
+    /// `MOV Vx, nn`
+    MovConst(u8, u8),
+
+    /// `ADD Vx, nn`
+    AddConst(u8, u8),
+
+    /// `MOV Vx, Vy`
+    MovReg(u8, u8),
+
+    /// `OR Vx, Vy`
+    Or(u8, u8),
+
+    /// `AND Vx, Vy`
+    And(u8, u8),
+
+    /// `XOR Vx, Vy`
+    Xor(u8, u8),
+
+    /// `ADD Vx, Vy`
+    AddReg(u8, u8),
+
+    /// `SUB Vx, Vy`
+    Sub(u8, u8),
+
+    /// `SHR Vx`
+    Shr(u8),
+
+    /// `SUBN Vx, Vy`
+    Subn(u8, u8),
+
+    /// `SHL Vx`
+    Shl(u8),
+
+    /// `MOV I, nnn`
+    MovI(u16),
+
+    /// `JPC nnn`
+    Jpc(u16),
+
+    /// `RAND Vx, nn`
+    Rand(u8, u8),
+
+    /// `DRAW Vx, Vy, n`
+    Draw(u8, u8, u8),
+
+    /// `GDL Vx`, which reads the delay timer
+    Gdl(u8),
+
+    /// `KEY Vx`, which waits for a key press
+    Key(u8),
+
+    /// `SDL Vx`, which sets the delay timer
+    Sdl(u8),
+
+    /// `SND Vx`, which sets the sound timer
+    Snd(u8),
+
+    /// `ADD I, Vx`
+    AddI(u8),
+
+    /// `SCH Vx`, which points `I` at a small font character
+    Sch(u8),
+
+    /// `LCH Vx`, which points `I` at a large font
+    /// character (SuperChip)
+    Lch(u8),
+
+    /// `BCD Vx`
+    Bcd(u8),
+
+    /// `RDP Vx`, which stores registers to memory
+    Rdp(u8),
+
+    /// `RLD Vx`, which loads registers from memory
+    Rld(u8),
+
+    /// `MOVL I, nnnn`, which loads a full 16-bit address
+    /// into `I` and is four bytes long
+    Movl(u16)
+}
+
+//implementation
+impl Instr {
+    /// Determines whether the instruction is a skip, which
+    /// may pass over the instruction that follows it
+    ///
+    /// # Returns
+    ///
+    /// Whether the instruction is one of the `SKIP` forms
+    pub fn is_skip(&self) -> bool {
+        return matches!(*self,
+                        Instr::SkipEqConst(..) | Instr::SkipNeConst(..) |
+                        Instr::SkipEqReg(..) | Instr::SkipNeReg(..) |
+                        Instr::SkipKeyDown(_) | Instr::SkipKeyUp(_));
+    }
+}
+
+//Display implementation
+//(the text uses exactly the mnemonics and operand syntax
+//that the `Assembler` accepts, so it reassembles)
+impl fmt::Display for Instr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Instr::Cls => write!(f, "CLS"),
+            Instr::Ret => write!(f, "RET"),
+            Instr::Scr => write!(f, "SCR"),
+            Instr::Scl => write!(f, "SCL"),
+            Instr::Exit => write!(f, "EXIT"),
+            Instr::Low => write!(f, "LOW"),
+            Instr::High => write!(f, "HIGH"),
+            Instr::Jmp(a) => write!(f, "JMP ${:03X}", a),
+            Instr::Call(a) => write!(f, "CALL ${:03X}", a),
+            Instr::SkipEqConst(x, nn) => {
+                write!(f, "SKIP.EQ V{:X}, ${:02X}", x, nn)
+            },
+            Instr::SkipNeConst(x, nn) => {
+                write!(f, "SKIP.NE V{:X}, ${:02X}", x, nn)
+            },
+            Instr::SkipEqReg(x, y) => write!(f, "SKIP.EQ V{:X}, V{:X}", x, y),
+            Instr::SkipNeReg(x, y) => write!(f, "SKIP.NE V{:X}, V{:X}", x, y),
+            Instr::SkipKeyDown(x) => write!(f, "SKIP.KD V{:X}", x),
+            Instr::SkipKeyUp(x) => write!(f, "SKIP.KU V{:X}", x),
+            Instr::MovConst(x, nn) => write!(f, "MOV V{:X}, ${:02X}", x, nn),
+            Instr::AddConst(x, nn) => write!(f, "ADD V{:X}, ${:02X}", x, nn),
+            Instr::MovReg(x, y) => write!(f, "MOV V{:X}, V{:X}", x, y),
+            Instr::Or(x, y) => write!(f, "OR V{:X}, V{:X}", x, y),
+            Instr::And(x, y) => write!(f, "AND V{:X}, V{:X}", x, y),
+            Instr::Xor(x, y) => write!(f, "XOR V{:X}, V{:X}", x, y),
+            Instr::AddReg(x, y) => write!(f, "ADD V{:X}, V{:X}", x, y),
+            Instr::Sub(x, y) => write!(f, "SUB V{:X}, V{:X}", x, y),
+            Instr::Shr(x) => write!(f, "SHR V{:X}", x),
+            Instr::Subn(x, y) => write!(f, "SUBN V{:X}, V{:X}", x, y),
+            Instr::Shl(x) => write!(f, "SHL V{:X}", x),
+            Instr::MovI(a) => write!(f, "MOV I, ${:03X}", a),
+            Instr::Jpc(a) => write!(f, "JPC ${:03X}", a),
+            Instr::Rand(x, nn) => write!(f, "RAND V{:X}, ${:02X}", x, nn),
+            Instr::Draw(x, y, n) => {
+                write!(f, "DRAW V{:X}, V{:X}, #{}", x, y, n)
+            },
+            Instr::Gdl(x) => write!(f, "GDL V{:X}", x),
+            Instr::Key(x) => write!(f, "KEY V{:X}", x),
+            Instr::Sdl(x) => write!(f, "SDL V{:X}", x),
+            Instr::Snd(x) => write!(f, "SND V{:X}", x),
+            Instr::AddI(x) => write!(f, "ADD I, V{:X}", x),
+            Instr::Sch(x) => write!(f, "SCH V{:X}", x),
+            Instr::Lch(x) => write!(f, "LCH V{:X}", x),
+            Instr::Bcd(x) => write!(f, "BCD V{:X}", x),
+            Instr::Rdp(x) => write!(f, "RDP V{:X}", x),
+            Instr::Rld(x) => write!(f, "RLD V{:X}", x),
+            Instr::Movl(a) => write!(f, "MOVL I, ${:04X}", a)
+        }
+    }
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the Instr enum
+    use super::*;
+
+    //this test checks formatting a few instructions
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Instr::Cls), "CLS");
+        assert_eq!(format!("{}", Instr::AddReg(0xA, 0xB)), "ADD VA, VB");
+        assert_eq!(format!("{}", Instr::MovI(0x123)), "MOV I, $123");
+        assert_eq!(format!("{}", Instr::Draw(0, 1, 5)), "DRAW V0, V1, #5");
+        assert_eq!(format!("{}", Instr::SkipKeyDown(3)), "SKIP.KD V3");
+        assert_eq!(format!("{}", Instr::Jmp(0x2A0)), "JMP $2A0");
+        assert_eq!(format!("{}", Instr::Movl(0x200)), "MOVL I, $0200");
+        assert!(Instr::SkipNeReg(1, 2).is_skip());
+        assert!(!Instr::Jmp(0x200).is_skip());
+    }
+}
+
+//end of file
@@ -0,0 +1,134 @@
+/*
+ * listing.rs
+ * Defines a struct that records an assembly listing
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use std::fmt;
+
+/// A listing of assembled code, pairing each source
+/// line with its address and the bytes generated for it
+pub struct Listing {
+    /// The address, bytes, and source text of each line
+    entries: Vec<(u16, Vec<u8>, String)>
+}
+
+//implementation
+impl Listing {
+    /// Constructs a new, empty `Listing` instance
+    ///
+    /// # Returns
+    ///
+    /// A new `Listing` instance with no entries
+    pub fn new() -> Self {
+        return Listing {
+            entries: Vec::new()
+        };
+    }
+
+    /// Adds an entry to the listing
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - The address the entry starts at
+    /// * `bytes` - The bytes generated for the entry
+    /// * `src` - The source text of the entry
+    pub fn add_entry(&mut self, addr: u16, bytes: &[u8], src: &str) {
+        self.entries.push((addr, bytes.to_vec(), String::from(src.trim())));
+    }
+
+    /// Iterates over the entries in the listing
+    ///
+    /// # Returns
+    ///
+    /// An iterator over `(address, bytes, source)` triples,
+    /// in the order they were added
+    pub fn iter(&self) -> impl Iterator<Item = (u16, &[u8], &str)> {
+        return self.entries.iter()
+                    .map(|(a, b, s)| (*a, b.as_slice(), s.as_str()));
+    }
+
+    /// Gets the number of entries in the listing
+    ///
+    /// # Returns
+    ///
+    /// The number of entries in the listing
+    pub fn len(&self) -> usize {
+        return self.entries.len();
+    }
+
+    /// Gets whether the listing has no entries
+    ///
+    /// # Returns
+    ///
+    /// Whether the listing is empty
+    pub fn is_empty(&self) -> bool {
+        return self.entries.is_empty();
+    }
+}
+
+//Default implementation
+impl Default for Listing {
+    fn default() -> Self {
+        return Listing::new();
+    }
+}
+
+//Display implementation
+impl fmt::Display for Listing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (addr, bytes, src) in &self.entries {
+            //format the bytes
+            let hex: String = bytes.iter()
+                                   .map(|b| format!("{:02X}", b))
+                                   .collect();
+
+            //and write the line
+            writeln!(f, "{:04X}  {:<4}   {}", addr, hex, src)?;
+        }
+
+        return Ok(());
+    }
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the Listing struct
+    use super::*;
+
+    //this test checks listing formatting
+    #[test]
+    fn test_listing_format() {
+        let mut lst = Listing::new();
+        lst.add_entry(0x200, &[0x00, 0xE0], "  CLS");
+        lst.add_entry(0x202, &[], "_loop:");
+        lst.add_entry(0x202, &[0xFF], "$FF ; data");
+        assert_eq!(lst.len(), 3);
+        let addrs: Vec<u16> = lst.iter().map(|e| e.0).collect();
+        assert_eq!(addrs, vec![0x200, 0x202, 0x202]);
+        assert_eq!(format!("{}", lst),
+                   "0200  00E0   CLS\n\
+                    0202         _loop:\n\
+                    0202  FF     $FF ; data\n");
+    }
+}
+
+//end of file
@@ -23,9 +23,37 @@
 //exports
 mod addr_table;
 pub use addr_table::AddrTable;
+mod reg_table;
+pub use reg_table::RegTable;
+mod section;
+pub use section::Section;
+mod section_map;
+pub use section_map::SectionMap;
 mod preprocessor;
 pub use preprocessor::Preprocessor;
+mod asm_options;
+pub use asm_options::AsmOptions;
 mod assembler;
 pub use assembler::Assembler;
+mod assemble_full;
+pub use assemble_full::assemble_full;
+mod assemble_to_vec;
+pub use assemble_to_vec::assemble_to_vec;
+mod assemble_web;
+pub use assemble_web::assemble_web;
+mod listing;
+pub use listing::Listing;
+mod ihex;
+pub use ihex::to_ihex;
+mod hex_dump;
+pub use hex_dump::hex_dump;
+mod disassembler;
+pub use disassembler::Disassembler;
+mod instr;
+pub use instr::Instr;
+mod decode_opcode;
+pub use decode_opcode::decode_opcode;
+mod encode_instr;
+pub use encode_instr::encode_instr;
 
 //end of file
@@ -23,7 +23,16 @@
 //usage statements
 use super::super::lex::*;
 use super::super::error::LexerError;
+use super::super::error::AddrError;
+use super::super::util::constants;
+use super::super::util::Sigils;
+use super::super::util::Position;
+use super::super::util::Variant;
 use super::AddrTable;
+use super::RegTable;
+use super::Section;
+use super::SectionMap;
+use std::mem;
 
 /// Preprocesses Chip-8 assembly code
 pub struct Preprocessor {
@@ -31,7 +40,22 @@ pub struct Preprocessor {
     lexer: PrepLexer,
 
     /// The current token being processed
-    cur_token: Token
+    cur_token: Token,
+
+    /// The register aliases defined in the code
+    aliases: RegTable,
+
+    /// The global label that local labels are scoped to
+    scope: String,
+
+    /// The code and data sections marked in the code
+    sections: SectionMap,
+
+    /// The scoped name and position of each label reference
+    refs: Vec<(String, Position)>,
+
+    /// The lexer errors found while preprocessing
+    errors: Vec<LexerError>
 }
 
 //implementation
@@ -44,17 +68,57 @@ impl Preprocessor {
     /// 
     /// # Returns
     ///
-    /// A new `Preprocessor` instance with the given code,
-    /// wrapped in a `Result`
-    pub fn new(new_code: &str) -> Result<Self, LexerError> {
-        let mut lex = PrepLexer::new(new_code);
-        let tok = lex.get_next_token()?;
+    /// A new `Preprocessor` instance with the given code
+    pub fn new(new_code: &str) -> Self {
+        return Preprocessor::with_origin(new_code, constants::MEM_START);
+    }
+
+    /// Constructs a new `Preprocessor` instance that
+    /// assigns label addresses starting at a given origin
+    ///
+    /// # Arguments
+    ///
+    /// * `new_code` - The code to preprocess
+    /// * `origin` - The address the program is loaded at
+    ///
+    /// # Returns
+    ///
+    /// A new `Preprocessor` instance with the given properties
+    pub fn with_origin(new_code: &str, origin: u16) -> Self {
+        return Preprocessor::with_sigils(new_code, origin, &Sigils::new());
+    }
+
+    /// Constructs a new `Preprocessor` instance that assigns label
+    /// addresses starting at a given origin and uses the given
+    /// comment and literal markers
+    ///
+    /// # Arguments
+    ///
+    /// * `new_code` - The code to preprocess
+    /// * `origin` - The address the program is loaded at
+    /// * `sigils` - The markers that start comments and literals
+    ///
+    /// # Returns
+    ///
+    /// A new `Preprocessor` instance with the given properties
+    pub fn with_sigils(new_code: &str, origin: u16, sigils: &Sigils)
+        -> Self {
+        let mut ret = Preprocessor {
+            lexer: PrepLexer::with_sigils(new_code, origin, sigils),
+            cur_token: Token::new(TokenType::EndOfInput,
+                                  Variant::Text(String::new())),
+            aliases: RegTable::new(),
+            scope: String::new(),
+            sections: SectionMap::new(),
+            refs: Vec::new(),
+            errors: Vec::new()
+        };
+
+        //get the first token
+        ret.cur_token = ret.next_token();
 
         //and return the instance
-        return Ok(Preprocessor {
-            lexer: lex,
-            cur_token: tok 
-        });
+        return ret;
     }
 
     /// Preprocesses the source code given to the lexer 
@@ -64,46 +128,152 @@ impl Preprocessor {
     /// This method will panic if a non-text token
     /// is generated by the lexer. 
     ///
+    /// Lexer errors do not stop preprocessing, so every one
+    /// in the code is found.
+    ///
     /// # Returns
     ///
     /// An `AddrTable` instance containing the preprocessed text,
-    /// wrapped in a `Result`
-    pub fn process(&mut self) -> Result<AddrTable, LexerError>  {
+    /// or every lexer error that was found
+    pub fn process(&mut self) -> Result<AddrTable, Vec<LexerError>> {
         //create the table
         let mut ret = AddrTable::new();
 
-        //add the first token
-        if self.cur_token.get_type() != TokenType::EndOfInput {
-            ret.add_entry(&self.cur_token.get_value().as_text().unwrap(),
-                            self.lexer.get_address());
-        }
-
         //loop and preprocess the text
-        loop {
-            //get the current token
-            self.cur_token = self.lexer.get_next_token()?;
-
-            //discard labels
-            while self.cur_token.get_type() == TokenType::Label {
-                self.cur_token = self.lexer.get_next_token()?;
+        while self.cur_token.get_type() != TokenType::EndOfInput {
+            //handle the current token
+            if self.cur_token.get_type() == TokenType::LblDef {
+                self.define(&mut ret);
+            } else if self.cur_token.get_type() == TokenType::Label {
+                self.reference();
+            } else if self.cur_token.get_type() == TokenType::RegDef {
+                self.alias();
+            } else if self.cur_token.get_type() == TokenType::Section {
+                self.section();
             }
 
-            //add the token to the table if it's not an EOI or 
-            //label reference token
-            if self.cur_token.get_type() != TokenType::EndOfInput{
-                let t = self.cur_token.get_value().as_text().unwrap();
-                ret.add_entry(&t, self.lexer.get_address()); 
-            }
+            //and get the next token
+            self.cur_token = self.next_token();
+        }
+
+        //report the lexer errors instead of the table
+        if !self.errors.is_empty() {
+            return Err(mem::take(&mut self.errors));
+        }
+
+        //end the last section at the end of the program
+        self.sections.finish(self.lexer.get_address());
 
-            //and determine whether to end the loop
-            if self.cur_token.get_type() == TokenType::EndOfInput {
-                break;
+        //label the font, unless the code uses the label itself
+        if let Some(base) = self.lexer.get_font_base() {
+            if !ret.has_entry(constants::FONT_LABEL) {
+                ret.add_entry(constants::FONT_LABEL, base);
             }
         }
 
         //and return the table
         return Ok(ret);
     }
+
+    /// Gets the register aliases defined in the code
+    ///
+    /// # Returns
+    ///
+    /// The register aliases found by the last call to `process`
+    pub fn get_aliases(&self) -> &RegTable {
+        return &self.aliases;
+    }
+
+    /// Gets the code and data sections marked in the code
+    ///
+    /// # Returns
+    ///
+    /// The sections found by the last call to `process`
+    pub fn get_sections(&self) -> &SectionMap {
+        return &self.sections;
+    }
+
+    /// Finds the label references that no label definition matches
+    ///
+    /// # Argument
+    ///
+    /// * `table` - The table returned by `process`
+    ///
+    /// # Returns
+    ///
+    /// An error for each reference to an undefined label,
+    /// in the order the references appear
+    pub fn undefined_labels(&self, table: &AddrTable) -> Vec<AddrError> {
+        return self.refs.iter()
+                    .filter(|(name, _)| !table.has_entry(name))
+                    .map(|(name, pos)| AddrError::new(name)
+                                        .with_position(*pos))
+                    .collect();
+    }
+
+    /// Starts the section named by the current section marker
+    fn section(&mut self) {
+        let name = self.cur_token.get_value_ref().as_text().unwrap();
+        if let Some(section) = Section::from_name(&name) {
+            self.sections.start(section, self.lexer.get_address());
+        }
+    }
+
+    /// Adds the current label definition to a table
+    ///
+    /// # Argument
+    ///
+    /// * `table` - The table to add the label to
+    fn define(&mut self, table: &mut AddrTable) {
+        //get the label
+        let lbl = self.cur_token.get_value_ref().as_text().unwrap();
+
+        //global labels start a new scope for local labels
+        if !lbl.starts_with(constants::LOCAL_LABEL_CHAR) {
+            self.scope = lbl.clone();
+        }
+
+        //and add the label under its scoped name
+        table.add_entry(&AddrTable::scoped_name(&self.scope, &lbl),
+                        self.lexer.get_address());
+    }
+
+    /// Records the current label reference, scoped to the
+    /// global label it appears under
+    fn reference(&mut self) {
+        let lbl = self.cur_token.get_value_ref().as_text().unwrap();
+        self.refs.push((AddrTable::scoped_name(&self.scope, &lbl),
+                        self.lexer.get_token_position()));
+    }
+
+    /// Gets the next token from the lexer, recording and
+    /// skipping past any lexer errors
+    ///
+    /// # Returns
+    ///
+    /// The next token that lexed without an error
+    fn next_token(&mut self) -> Token {
+        loop {
+            match self.lexer.get_next_token() {
+                Ok(t) => return t,
+                Err(le) => self.errors.push(le)
+            };
+        }
+    }
+
+    /// Records a register alias definition
+    fn alias(&mut self) {
+        //get the name of the alias
+        let name = self.cur_token.get_value_ref().as_text().unwrap();
+
+        //and record the register that follows it
+        //(a missing register is reported by the assembler)
+        let tok = self.next_token();
+        if tok.get_type() == TokenType::Register {
+            let reg = tok.get_value_ref().as_text().unwrap();
+            self.aliases.add_entry(&name, &reg);
+        }
+    }
 }
 
 //unit tests
@@ -111,7 +281,6 @@ impl Preprocessor {
 mod tests {
     //imports
     use super::*;
-    use super::super::super::util::constants;
 
     //define the code string
     const CODE: &str = "_start: 
@@ -124,16 +293,196 @@ mod tests {
     //this test checks preprocessing code
     #[test]
     fn test_preprocess() {
-        let mut prep = Preprocessor::new(CODE).unwrap();
+        let mut prep = Preprocessor::new(CODE);
         let tab = prep.process().unwrap();
         assert!(tab.has_entry("_START"));
         assert!(tab.has_entry("_START"));
-        assert_eq!(tab.has_entry("_END"), false);
+        assert!(!tab.has_entry("_END"));
         let start_addr = tab.get_entry("_START").unwrap();
         assert_eq!(start_addr, constants::MEM_START);
         let loop_addr = tab.get_entry("_LOOP").unwrap();
         assert_eq!(loop_addr, constants::MEM_START + 2);
     }
+
+    //this test checks that literal sizes match the assembler
+    #[test]
+    fn test_literal_sizes() {
+        let code = "MOV I, _a
+                    #255
+                    _a:
+                    #256
+                    _b:
+                    SKIP.EQ V0, #300
+                    _c:";
+        let mut prep = Preprocessor::new(code);
+        let tab = prep.process().unwrap();
+        assert_eq!(tab.get_entry("_A").unwrap(), constants::MEM_START + 3);
+        assert_eq!(tab.get_entry("_B").unwrap(), constants::MEM_START + 5);
+        assert_eq!(tab.get_entry("_C").unwrap(), constants::MEM_START + 7);
+    }
+
+    //this test checks recording register aliases
+    #[test]
+    fn test_register_aliases() {
+        let code = "COUNTER REG V3
+                    MOV COUNTER, #1
+                    _a:
+                    ADD V1, COUNTER
+                    _b:
+                    DB $01, $02
+                    CLS
+                    _c:";
+        let mut prep = Preprocessor::new(code);
+        let tab = prep.process().unwrap();
+        assert_eq!(prep.get_aliases().get_entry("COUNTER"), Some("V3"));
+        assert_eq!(tab.get_entry("_A").unwrap(), constants::MEM_START + 2);
+        assert_eq!(tab.get_entry("_B").unwrap(), constants::MEM_START + 4);
+        assert_eq!(tab.get_entry("_C").unwrap(), constants::MEM_START + 8);
+    }
+
+    //this test checks scoping local labels
+    #[test]
+    fn test_local_labels() {
+        let code = "_draw:\n.loop:\nCLS\nJMP .loop\n\
+                    _erase:\n.loop:\nRET\nJMP .loop";
+        let mut prep = Preprocessor::new(code);
+        let tab = prep.process().unwrap();
+        assert_eq!(tab.get_entry("_DRAW.LOOP").unwrap(), constants::MEM_START);
+        assert_eq!(tab.get_entry("_ERASE.LOOP").unwrap(),
+                   constants::MEM_START + 4);
+        assert!(!tab.has_entry(".LOOP"));
+    }
+
+    //this test checks sizing arithmetic expressions
+    #[test]
+    fn test_expressions() {
+        let code = "#250+#10 _a: (#1+#2) _b: DS #2*#3 _c: MOV I, _a+#1 _d:";
+        let mut prep = Preprocessor::new(code);
+        let tab = prep.process().unwrap();
+        assert_eq!(tab.get_entry("_A").unwrap(), constants::MEM_START + 2);
+        assert_eq!(tab.get_entry("_B").unwrap(), constants::MEM_START + 3);
+        assert_eq!(tab.get_entry("_C").unwrap(), constants::MEM_START + 9);
+        assert_eq!(tab.get_entry("_D").unwrap(), constants::MEM_START + 11);
+    }
+
+    //this test checks that single-nibble hex literals take a byte
+    #[test]
+    fn test_nibble_hex_literal() {
+        let code = "_a: $F _b: $00FF _c: $FFF _d:";
+        let mut prep = Preprocessor::new(code);
+        let tab = prep.process().unwrap();
+        assert_eq!(tab.get_entry("_A").unwrap(), constants::MEM_START);
+        assert_eq!(tab.get_entry("_B").unwrap(), constants::MEM_START + 1);
+        assert_eq!(tab.get_entry("_C").unwrap(), constants::MEM_START + 2);
+        assert_eq!(tab.get_entry("_D").unwrap(), constants::MEM_START + 4);
+    }
+
+    //this test checks that adding to the I register takes one word
+    #[test]
+    fn test_add_index() {
+        let code = "ADD I, V5
+                    _a:
+                    ADD I, V0
+                    DB $01
+                    _b:";
+        let mut prep = Preprocessor::new(code);
+        let tab = prep.process().unwrap();
+        assert_eq!(tab.get_entry("_A").unwrap(), constants::MEM_START + 2);
+        assert_eq!(tab.get_entry("_B").unwrap(), constants::MEM_START + 5);
+    }
+
+    //this test checks sizing data lists with trailing comments
+    #[test]
+    fn test_data_comments() {
+        let code = "_a: DB #1, #2 ; two bytes
+                    _b: DB #3, ; a list carried on
+                           #4, #5 ;{ with a block ;} ; and a comment
+                    _c: DW $1234, _a ; a word and a label
+                    _d: DB #6;no space
+                    _e:";
+        let mut prep = Preprocessor::new(code);
+        let tab = prep.process().unwrap();
+        assert_eq!(tab.get_entry("_B").unwrap(), constants::MEM_START + 2);
+        assert_eq!(tab.get_entry("_C").unwrap(), constants::MEM_START + 5);
+        assert_eq!(tab.get_entry("_D").unwrap(), constants::MEM_START + 9);
+        assert_eq!(tab.get_entry("_E").unwrap(), constants::MEM_START + 10);
+    }
+
+    //this test checks sizing data lists continued with a backslash
+    #[test]
+    fn test_continuation() {
+        let code = "_a: DW $0102, \\
+                           $0304, \\  ; blanks and a comment
+                           $0506
+                    _b: DB #1 \\ _c: DB #2
+                    _d:";
+        let mut prep = Preprocessor::new(code);
+        let tab = prep.process().unwrap();
+        assert_eq!(tab.get_entry("_B").unwrap(), constants::MEM_START + 6);
+        assert_eq!(tab.get_entry("_C").unwrap(), constants::MEM_START + 7);
+        assert_eq!(tab.get_entry("_D").unwrap(), constants::MEM_START + 8);
+    }
+
+    //this test checks sizing string literals in data lists
+    #[test]
+    fn test_str_lits() {
+        let code = "_a: DB \"a;b,c.\", #0 ; the string is data
+                    _b: DB \"\\\", \"_c:\"
+                    _d: DW \"x\"
+                    _e:";
+        let mut prep = Preprocessor::new(code);
+        let tab = prep.process().unwrap();
+        assert_eq!(tab.get_entry("_B").unwrap(), constants::MEM_START + 7);
+        assert!(!tab.has_entry("_C"));
+        assert_eq!(tab.get_entry("_D").unwrap(), constants::MEM_START + 11);
+        assert_eq!(tab.get_entry("_E").unwrap(), constants::MEM_START + 11);
+    }
+
+    //this test checks sizing and labeling the font
+    #[test]
+    fn test_font_data() {
+        let code = "CLS
+                    FONT_DATA
+                    _after:
+                    FONT_DATA";
+        let mut prep = Preprocessor::new(code);
+        let tab = prep.process().unwrap();
+        assert_eq!(tab.get_entry("_FONT_DATA").unwrap(),
+                   constants::MEM_START + 2);
+        assert_eq!(tab.get_entry("_after").unwrap(),
+                   constants::MEM_START + 82);
+        assert_eq!(prep.lexer.get_address(), constants::MEM_START + 162);
+    }
+
+    //this test checks finding references to undefined labels
+    #[test]
+    fn test_undefined_labels() {
+        let code = "JMP _first
+                    _a:
+                    .loop: JMP .loop
+                    _b: JMP .loop
+                    JMP _a";
+        let mut prep = Preprocessor::new(code);
+        let tab = prep.process().unwrap();
+        assert!(!tab.has_entry("_first"));
+        let errs = prep.undefined_labels(&tab);
+        assert_eq!(errs.len(), 2);
+        assert_eq!(format!("{}", errs[0]),
+                   "(1:5): Could not get the address of the label _first");
+        assert_eq!(format!("{}", errs[1]),
+                   "(4:29): Could not get the address of the label _b.loop");
+    }
+
+    //this test checks that preprocessing finds every lexer error
+    #[test]
+    fn test_lexer_errors() {
+        let code = "@\n_a:\nCLS\n!";
+        let mut prep = Preprocessor::new(code);
+        let errs = prep.process().err().unwrap();
+        assert_eq!(errs.len(), 2);
+        assert_eq!(errs[0].get_line(), 1);
+        assert_eq!(errs[1].get_line(), 4);
+    }
 }
 
 //end of file
@@ -0,0 +1,109 @@
+/*
+ * reg_table.rs
+ * Defines a struct that matches register aliases to registers
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use std::collections::HashMap;
+
+/// Manages register alias-to-register relationships
+#[derive(Clone)]
+pub struct RegTable {
+    /// The table data
+    data: HashMap<String, String>
+}
+
+//implementation
+impl RegTable {
+    /// Constructs a new `RegTable` instance
+    ///
+    /// # Returns
+    ///
+    /// A new `RegTable` instance with no data
+    pub fn new() -> Self {
+        return RegTable {
+            data: HashMap::new()
+        };
+    }
+
+    /// Adds an entry to the table
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - The alias to add to the table
+    /// * `reg` - The name of the register the alias refers to
+    pub fn add_entry(&mut self, alias: &str, reg: &str) {
+        self.data.insert(String::from(alias), String::from(reg));
+    }
+
+    /// Determines whether a given alias exists in the table
+    ///
+    /// # Argument
+    ///
+    /// * `alias` - The alias to check
+    ///
+    /// # Returns
+    ///
+    /// Whether the given alias exists in the table
+    pub fn has_entry(&self, alias: &str) -> bool {
+        return self.data.contains_key(alias);
+    }
+
+    /// Gets the register name for a given alias
+    ///
+    /// # Argument
+    ///
+    /// * `alias` - The alias to get the register for
+    ///
+    /// # Returns
+    ///
+    /// The name of the register the alias refers to,
+    /// or `None` if the alias is not defined
+    pub fn get_entry(&self, alias: &str) -> Option<&str> {
+        return self.data.get(alias).map(|r| r.as_str());
+    }
+}
+
+//Default implementation
+impl Default for RegTable {
+    fn default() -> Self {
+        return RegTable::new();
+    }
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the RegTable struct
+    use super::*;
+
+    //this test checks adding and retrieving aliases
+    #[test]
+    fn test_add_retrieve_aliases() {
+        let mut t = RegTable::new();
+        assert!(!t.has_entry("COUNTER"));
+        t.add_entry("COUNTER", "V3");
+        assert!(t.has_entry("COUNTER"));
+        assert_eq!(t.get_entry("COUNTER"), Some("V3"));
+        assert_eq!(t.get_entry("SPEED"), None);
+    }
+}
+
+//end of file
@@ -0,0 +1,94 @@
+/*
+ * section.rs
+ * Defines an enum that represents a kind of program section
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use std::fmt;
+
+/// The kind of a section of a program
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Section {
+    /// Instructions, started with `.code`
+    Code,
+
+    /// Data, started with `.data`
+    Data
+}
+
+//implementation
+impl Section {
+    /// Gets the section with a given name
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the section, without its period
+    ///
+    /// # Returns
+    ///
+    /// The section with the name (ignoring case), or `None`
+    /// if no section has the name
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name.eq_ignore_ascii_case("code") {
+            return Some(Section::Code);
+        } else if name.eq_ignore_ascii_case("data") {
+            return Some(Section::Data);
+        } else {
+            return None;
+        }
+    }
+
+    /// Gets the name of the section
+    ///
+    /// # Returns
+    ///
+    /// The name of the section, without its period
+    pub fn get_name(&self) -> &str {
+        return match *self {
+            Section::Code => "code",
+            Section::Data => "data"
+        };
+    }
+}
+
+//Display implementation
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, ".{}", self.get_name())
+    }
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the Section enum
+    use super::*;
+
+    //this test checks looking up sections by name
+    #[test]
+    fn test_section_names() {
+        assert_eq!(Section::from_name("data"), Some(Section::Data));
+        assert_eq!(Section::from_name("CODE"), Some(Section::Code));
+        assert_eq!(Section::from_name("text"), None);
+        assert_eq!(format!("{}", Section::Data), ".data");
+    }
+}
+
+//end of file
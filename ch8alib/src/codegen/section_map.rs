@@ -0,0 +1,158 @@
+/*
+ * section_map.rs
+ * Defines a struct that records the sections of a program
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use super::Section;
+
+/// Records which address ranges of a program are code and which
+/// are data, as marked by `.code` and `.data`
+#[derive(Clone, Debug, PartialEq)]
+pub struct SectionMap {
+    /// The finished ranges, as `(start, end, section)` triples
+    /// with exclusive ends
+    ranges: Vec<(u16, u16, Section)>,
+
+    /// The section being recorded and the address it started at
+    open: Option<(u16, Section)>
+}
+
+//implementation
+impl SectionMap {
+    /// Constructs a new, empty `SectionMap` instance
+    ///
+    /// # Returns
+    ///
+    /// A new `SectionMap` instance with no sections
+    pub fn new() -> Self {
+        return SectionMap {
+            ranges: Vec::new(),
+            open: None
+        };
+    }
+
+    /// Starts a new section, ending the one before it
+    ///
+    /// # Arguments
+    ///
+    /// * `section` - The kind of the new section
+    /// * `addr` - The address the new section starts at
+    pub fn start(&mut self, section: Section, addr: u16) {
+        self.finish(addr);
+        self.open = Some((addr, section));
+    }
+
+    /// Ends the section being recorded
+    ///
+    /// # Argument
+    ///
+    /// * `addr` - The address just past the end of the section
+    pub fn finish(&mut self, addr: u16) {
+        //empty sections are not recorded
+        if let Some((start, section)) = self.open.take() {
+            if addr > start {
+                self.ranges.push((start, addr, section));
+            }
+        }
+    }
+
+    /// Gets the section that an address is in
+    ///
+    /// # Argument
+    ///
+    /// * `addr` - The address to look up
+    ///
+    /// # Returns
+    ///
+    /// The section containing the address, or `None`
+    /// if no section marker covers it
+    pub fn get_section(&self, addr: u16) -> Option<Section> {
+        return self.ranges.iter()
+                    .find(|&&(start, end, _)| (start <= addr) && (addr < end))
+                    .map(|&(_, _, section)| section);
+    }
+
+    /// Determines whether an address is inside a data section
+    ///
+    /// # Argument
+    ///
+    /// * `addr` - The address to check
+    ///
+    /// # Returns
+    ///
+    /// Whether the address was marked as data
+    pub fn is_data(&self, addr: u16) -> bool {
+        return self.get_section(addr) == Some(Section::Data);
+    }
+
+    /// Iterates over the recorded sections
+    ///
+    /// # Returns
+    ///
+    /// An iterator over `(start, end, section)` triples in address
+    /// order, where each end is the address just past the section
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u16, Section)> + '_ {
+        return self.ranges.iter().copied();
+    }
+
+    /// Gets whether no sections were recorded
+    ///
+    /// # Returns
+    ///
+    /// Whether the map has no sections
+    pub fn is_empty(&self) -> bool {
+        return self.ranges.is_empty();
+    }
+}
+
+//Default implementation
+impl Default for SectionMap {
+    fn default() -> Self {
+        return SectionMap::new();
+    }
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the SectionMap struct
+    use super::*;
+
+    //this test checks recording sections
+    #[test]
+    fn test_sections() {
+        let mut map = SectionMap::new();
+        map.start(Section::Code, 0x200);
+        map.start(Section::Data, 0x204);
+        map.start(Section::Code, 0x208);
+        map.start(Section::Data, 0x208);
+        map.finish(0x20A);
+        let ranges: Vec<_> = map.iter().collect();
+        assert_eq!(ranges, vec![(0x200, 0x204, Section::Code),
+                                (0x204, 0x208, Section::Data),
+                                (0x208, 0x20A, Section::Data)]);
+        assert!(map.is_data(0x204));
+        assert!(!map.is_data(0x202));
+        assert_eq!(map.get_section(0x20A), None);
+    }
+}
+
+//end of file
@@ -20,13 +20,23 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-//usage statement
+//usage statements
 use std::fmt;
+use super::super::util::mark_source;
+use super::super::util::Position;
 
 /// Created when a label does not have a defined address
 pub struct AddrError {
     /// The label that an address was not defined for
-    label: String 
+    label: String,
+
+    /// The position of the reference to the label
+    /// (`None` if the label was not looked up from the source)
+    pos: Option<Position>,
+
+    /// The source line the error was found on
+    /// (empty until attached with `set_source`)
+    src: String
 }
 
 //implementation
@@ -42,9 +52,55 @@ impl AddrError {
     /// A new `AddrError` instance with the given properties
     pub fn new(bad_label: &str) -> Self {
         return AddrError {
-            label: String::from(bad_label)
+            label: String::from(bad_label),
+            pos: None,
+            src: String::new()
         };
     }
+
+    /// Ties the error to the reference that triggered it
+    ///
+    /// # Argument
+    ///
+    /// * `new_pos` - The position of the reference to the label
+    ///
+    /// # Returns
+    ///
+    /// The error, with the given position
+    pub fn with_position(mut self, new_pos: Position) -> Self {
+        self.pos = Some(new_pos);
+        return self;
+    }
+
+    /// Gets the line number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The line number of the reference to the label, or
+    /// `None` if the error is not tied to a position
+    pub fn get_line(&self) -> Option<u32> {
+        return self.pos.map(|p| p.get_line());
+    }
+
+    /// Gets the column number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The column number of the reference to the label, or
+    /// `None` if the error is not tied to a position
+    pub fn get_column(&self) -> Option<u32> {
+        return self.pos.map(|p| p.get_column());
+    }
+
+    /// Attaches the offending line of source code to the error,
+    /// so that it is shown beneath the message
+    ///
+    /// # Arguments
+    ///
+    /// * `new_src` - The text of the line the error was found on
+    pub fn set_source(&mut self, new_src: &str) {
+        self.src = String::from(new_src);
+    }
 }
 
 //Debug implementation
@@ -57,8 +113,19 @@ impl fmt::Debug for AddrError {
 //Display implementation
 impl fmt::Display for AddrError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Could not get the address of the label {}", 
-               self.label)
+        //start with the position of the reference, if known
+        if let Some(pos) = self.pos {
+            write!(f, "({}): ", pos)?;
+        }
+
+        write!(f, "Could not get the address of the label {}",
+               self.label)?;
+
+        //and show the offending source line
+        if let (Some(pos), false) = (self.pos, self.src.is_empty()) {
+            write!(f, "{}", mark_source(&self.src, pos.get_column()))?;
+        }
+        return Ok(());
     }
 }
 
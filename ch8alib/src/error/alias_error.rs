@@ -0,0 +1,113 @@
+/*
+ * alias_error.rs
+ * Defines an error that is generated when
+ * an undefined register alias is used
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statement
+use std::fmt;
+use super::super::util::mark_source;
+use super::super::util::Position;
+
+/// Generated when an undefined register alias is used
+pub struct AliasError {
+    /// The alias that caused the error
+    alias: String,
+
+    /// The position the error was found at
+    pos: Position,
+
+    /// The source line the error was found on
+    /// (empty until attached with `set_source`)
+    src: String
+}
+
+//Implementation
+impl AliasError {
+    /// Constructs a new `AliasError` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `new_alias` - The alias that triggered the error
+    /// * `new_pos` - The position the error was found at
+    ///
+    /// # Returns
+    ///
+    /// A new `AliasError` instance with the given properties
+    pub fn new(new_alias: &str, new_pos: Position) -> Self {
+        return AliasError {
+            alias: String::from(new_alias),
+            pos: new_pos,
+            src: String::new()
+        };
+    }
+
+    /// Gets the line number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The line number the error was found on
+    pub fn get_line(&self) -> u32 {
+        return self.pos.get_line();
+    }
+
+    /// Gets the column number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The column number the error was found on
+    pub fn get_column(&self) -> u32 {
+        return self.pos.get_column();
+    }
+
+    /// Attaches the offending line of source code to the error,
+    /// so that it is shown beneath the message
+    ///
+    /// # Arguments
+    ///
+    /// * `new_src` - The text of the line the error was found on
+    pub fn set_source(&mut self, new_src: &str) {
+        self.src = String::from(new_src);
+    }
+}
+
+//Debug implementation
+impl fmt::Debug for AliasError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+//Display implementation
+impl fmt::Display for AliasError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}): undefined register alias {}",
+                self.pos, self.alias)?;
+
+        //show the offending source line
+        if !self.src.is_empty() {
+            write!(f, "{}",
+                   mark_source(&self.src, self.pos.get_column()))?;
+        }
+        return Ok(());
+    }
+}
+
+//end of file
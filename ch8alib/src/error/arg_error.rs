@@ -26,6 +26,9 @@ extern crate ch8_isa;
 
 //usage statements
 use std::fmt;
+use super::super::util::mark_source;
+use super::super::util::Position;
+use super::super::util::reg_name;
 use ch8_isa::data::Register;
 
 /// Generated when an instruction is given bad arguments
@@ -36,11 +39,12 @@ pub struct ArgError {
     /// The instruction that had the bad argument
     instr: String,
 
-    /// The line number of the bad argument
-    line: u32,
+    /// The position of the bad argument
+    pos: Position,
 
-    /// The column number of the bad argument
-    col: u32 
+    /// The source line the error was found on
+    /// (empty until attached with `set_source`)
+    src: String
 }
 
 //implementation
@@ -51,21 +55,48 @@ impl ArgError {
     ///
     /// * `new_arg` - The bad argument
     /// * `new_instr` - The instruction that had the bad argument 
-    /// * `new_line` - The line number of the bad argument 
-    /// * `new_col` - The column number of the bad argument
+    /// * `new_pos` - The position of the bad argument
     /// 
     /// # Returns
     ///
     /// A new `ArgError` instance with the given properties
     pub fn new(new_arg: &Register, new_instr: &str,
-               new_line: u32, new_col: u32) -> Self {
+               new_pos: Position) -> Self {
         return ArgError {
             bad_arg: new_arg.clone(),
             instr: String::from(new_instr),
-            line: new_line,
-            col: new_col 
+            pos: new_pos,
+            src: String::new()
         };
     }
+
+    /// Gets the line number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The line number the error was found on
+    pub fn get_line(&self) -> u32 {
+        return self.pos.get_line();
+    }
+
+    /// Gets the column number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The column number the error was found on
+    pub fn get_column(&self) -> u32 {
+        return self.pos.get_column();
+    }
+
+    /// Attaches the offending line of source code to the error,
+    /// so that it is shown beneath the message
+    ///
+    /// # Arguments
+    ///
+    /// * `new_src` - The text of the line the error was found on
+    pub fn set_source(&mut self, new_src: &str) {
+        self.src = String::from(new_src);
+    }
 }
 
 //Debug implementation
@@ -78,8 +109,21 @@ impl fmt::Debug for ArgError {
 //Display implementation
 impl fmt::Display for ArgError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}:{}): bad argument {:?} for {} instruction",
-                self.line, self.col, self.bad_arg, self.instr)
+        write!(f, "({}): bad argument {} for {} instruction",
+                self.pos, reg_name(&self.bad_arg), self.instr)?;
+
+        //explain where the I register can be used
+        if self.bad_arg == Register::I {
+            write!(f, " (I is only valid for index operations, \
+                       MOV I, NNN and ADD I, VX; use V0 to VF here)")?;
+        }
+
+        //show the offending source line
+        if !self.src.is_empty() {
+            write!(f, "{}",
+                   mark_source(&self.src, self.pos.get_column()))?;
+        }
+        return Ok(());
     }
 }
 
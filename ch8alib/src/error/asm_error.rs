@@ -30,6 +30,14 @@ use super::OpcodeError;
 use super::AddrError;
 use super::ArgError;
 use super::SkipError;
+use super::CondError;
+use super::OverflowError;
+use super::AliasError;
+use super::ExprError;
+use super::RangeError;
+use super::StrayError;
+use super::RegError;
+use super::SigilError;
 use ch8_isa::error::BinaryError;
 use std::fmt;
 
@@ -54,7 +62,107 @@ pub enum AsmError {
     Argument(ArgError),
 
     /// A skiptype error
-    Skip(SkipError)
+    Skip(SkipError),
+
+    /// A skip condition error
+    Condition(CondError),
+
+    /// A memory overflow error
+    Overflow(OverflowError),
+
+    /// A register alias error
+    Alias(AliasError),
+
+    /// An expression evaluation error
+    Expression(ExprError),
+
+    /// A value range error
+    Range(RangeError),
+
+    /// A stray token error
+    Stray(StrayError),
+
+    /// A malformed register error
+    Register(RegError),
+
+    /// A comment or literal marker error
+    Sigil(SigilError)
+}
+
+//implementation
+impl AsmError {
+    /// Gets the line number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The line number of the error, or `None` if the
+    /// error is not tied to a position in the source
+    pub fn get_line(&self) -> Option<u32> {
+        match *self {
+            AsmError::Lexer(ref le) => Some(le.get_line()),
+            AsmError::Parser(ref pe) => Some(pe.get_line()),
+            AsmError::Address(ref ae) => ae.get_line(),
+            AsmError::Opcode(ref oe) => Some(oe.get_line()),
+            AsmError::Argument(ref ae) => Some(ae.get_line()),
+            AsmError::Skip(ref se) => Some(se.get_line()),
+            AsmError::Condition(ref ce) => Some(ce.get_line()),
+            AsmError::Alias(ref ae) => Some(ae.get_line()),
+            AsmError::Expression(ref ee) => Some(ee.get_line()),
+            AsmError::Range(ref re) => Some(re.get_line()),
+            AsmError::Stray(ref se) => Some(se.get_line()),
+            AsmError::Register(ref re) => Some(re.get_line()),
+            _ => None
+        }
+    }
+
+    /// Gets the column number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The column number of the error, or `None` if the
+    /// error is not tied to a position in the source
+    pub fn get_column(&self) -> Option<u32> {
+        match *self {
+            AsmError::Lexer(ref le) => Some(le.get_column()),
+            AsmError::Parser(ref pe) => Some(pe.get_column()),
+            AsmError::Address(ref ae) => ae.get_column(),
+            AsmError::Opcode(ref oe) => Some(oe.get_column()),
+            AsmError::Argument(ref ae) => Some(ae.get_column()),
+            AsmError::Skip(ref se) => Some(se.get_column()),
+            AsmError::Condition(ref ce) => Some(ce.get_column()),
+            AsmError::Alias(ref ae) => Some(ae.get_column()),
+            AsmError::Expression(ref ee) => Some(ee.get_column()),
+            AsmError::Range(ref re) => Some(re.get_column()),
+            AsmError::Stray(ref se) => Some(se.get_column()),
+            AsmError::Register(ref re) => Some(re.get_column()),
+            _ => None
+        }
+    }
+
+    /// Attaches the offending line of source code to the error
+    ///
+    /// Errors that are not tied to a position are left unchanged
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - The text of the line the error was found on
+    pub fn set_source(&mut self, src: &str) {
+        match *self {
+            AsmError::Lexer(ref mut le) => le.set_source(src),
+            AsmError::Parser(ref mut pe) => pe.set_source(src),
+            AsmError::Address(ref mut ae) => ae.set_source(src),
+            AsmError::Opcode(ref mut oe) => oe.set_source(src),
+            AsmError::Argument(ref mut ae) => ae.set_source(src),
+            AsmError::Skip(ref mut se) => se.set_source(src),
+            AsmError::Condition(ref mut ce) => ce.set_source(src),
+            AsmError::Alias(ref mut ae) => ae.set_source(src),
+            AsmError::Expression(ref mut ee) => ee.set_source(src),
+            AsmError::Range(ref mut re) => re.set_source(src),
+            AsmError::Stray(ref mut se) => se.set_source(src),
+            AsmError::Register(ref mut re) => re.set_source(src),
+            _ => {}
+        }
+    }
 }
 
 //Debug implementation
@@ -67,7 +175,15 @@ impl fmt::Debug for AsmError {
             AsmError::Binary(ref be) => write!(f, "{:?}", be),
             AsmError::Address(ref ae) => write!(f, "{:?}", ae),
             AsmError::Argument(ref ae) => write!(f, "{:?}", ae),
-            AsmError::Skip(ref se) => write!(f, "{:?}", se)
+            AsmError::Skip(ref se) => write!(f, "{:?}", se),
+            AsmError::Condition(ref ce) => write!(f, "{:?}", ce),
+            AsmError::Overflow(ref oe) => write!(f, "{:?}", oe),
+            AsmError::Alias(ref ae) => write!(f, "{:?}", ae),
+            AsmError::Expression(ref ee) => write!(f, "{:?}", ee),
+            AsmError::Range(ref re) => write!(f, "{:?}", re),
+            AsmError::Stray(ref se) => write!(f, "{:?}", se),
+            AsmError::Register(ref re) => write!(f, "{:?}", re),
+            AsmError::Sigil(ref se) => write!(f, "{:?}", se)
         }
     }
 }
@@ -82,9 +198,109 @@ impl fmt::Display for AsmError {
             AsmError::Binary(ref be) => write!(f, "{}", be),
             AsmError::Address(ref ae) => write!(f, "{}", ae),
             AsmError::Argument(ref ae) => write!(f, "{}", ae),
-            AsmError::Skip(ref se) => write!(f, "{}", se)
+            AsmError::Skip(ref se) => write!(f, "{}", se),
+            AsmError::Condition(ref ce) => write!(f, "{}", ce),
+            AsmError::Overflow(ref oe) => write!(f, "{}", oe),
+            AsmError::Alias(ref ae) => write!(f, "{}", ae),
+            AsmError::Expression(ref ee) => write!(f, "{}", ee),
+            AsmError::Range(ref re) => write!(f, "{}", re),
+            AsmError::Stray(ref se) => write!(f, "{}", se),
+            AsmError::Register(ref re) => write!(f, "{}", re),
+            AsmError::Sigil(ref se) => write!(f, "{}", se)
         }
     }
 }
 
+//From implementations
+//(these let the inner error types be returned with `?`)
+impl From<LexerError> for AsmError {
+    fn from(err: LexerError) -> Self {
+        return AsmError::Lexer(err);
+    }
+}
+
+impl From<ParseError> for AsmError {
+    fn from(err: ParseError) -> Self {
+        return AsmError::Parser(err);
+    }
+}
+
+impl From<OpcodeError> for AsmError {
+    fn from(err: OpcodeError) -> Self {
+        return AsmError::Opcode(err);
+    }
+}
+
+impl From<BinaryError> for AsmError {
+    fn from(err: BinaryError) -> Self {
+        return AsmError::Binary(err);
+    }
+}
+
+impl From<AddrError> for AsmError {
+    fn from(err: AddrError) -> Self {
+        return AsmError::Address(err);
+    }
+}
+
+impl From<ArgError> for AsmError {
+    fn from(err: ArgError) -> Self {
+        return AsmError::Argument(err);
+    }
+}
+
+impl From<SkipError> for AsmError {
+    fn from(err: SkipError) -> Self {
+        return AsmError::Skip(err);
+    }
+}
+
+impl From<CondError> for AsmError {
+    fn from(err: CondError) -> Self {
+        return AsmError::Condition(err);
+    }
+}
+
+impl From<OverflowError> for AsmError {
+    fn from(err: OverflowError) -> Self {
+        return AsmError::Overflow(err);
+    }
+}
+
+impl From<AliasError> for AsmError {
+    fn from(err: AliasError) -> Self {
+        return AsmError::Alias(err);
+    }
+}
+
+impl From<ExprError> for AsmError {
+    fn from(err: ExprError) -> Self {
+        return AsmError::Expression(err);
+    }
+}
+
+impl From<RangeError> for AsmError {
+    fn from(err: RangeError) -> Self {
+        return AsmError::Range(err);
+    }
+}
+
+impl From<StrayError> for AsmError {
+    fn from(err: StrayError) -> Self {
+        return AsmError::Stray(err);
+    }
+}
+
+impl From<RegError> for AsmError {
+    fn from(err: RegError) -> Self {
+        return AsmError::Register(err);
+    }
+}
+
+impl From<SigilError> for AsmError {
+    fn from(err: SigilError) -> Self {
+        return AsmError::Sigil(err);
+    }
+}
+
 //end of file
@@ -0,0 +1,121 @@
+/*
+ * asm_warning.rs
+ * Defines a warning that is generated when
+ * code assembles but may not do what was meant
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statement
+use std::fmt;
+use super::super::util::mark_source;
+use super::super::util::Position;
+
+/// Generated when code assembles but may not do what was intended
+pub struct AsmWarning {
+    /// A description of the problem
+    msg: String,
+
+    /// The position the warning was found at
+    pos: Position,
+
+    /// The source line the warning was found on
+    /// (empty until attached with `set_source`)
+    src: String
+}
+
+//Implementation
+impl AsmWarning {
+    /// Constructs a new `AsmWarning` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `new_msg` - A description of the problem
+    /// * `new_pos` - The position the warning was found at
+    ///
+    /// # Returns
+    ///
+    /// A new `AsmWarning` instance with the given properties
+    pub fn new(new_msg: &str, new_pos: Position) -> Self {
+        return AsmWarning {
+            msg: String::from(new_msg),
+            pos: new_pos,
+            src: String::new()
+        };
+    }
+
+    /// Gets the description of the problem
+    ///
+    /// # Returns
+    ///
+    /// The description of the problem, without its position
+    pub fn get_message(&self) -> &str {
+        return &self.msg;
+    }
+
+    /// Gets the line number the warning was found on
+    ///
+    /// # Returns
+    ///
+    /// The line number the warning was found on
+    pub fn get_line(&self) -> u32 {
+        return self.pos.get_line();
+    }
+
+    /// Gets the column number the warning was found on
+    ///
+    /// # Returns
+    ///
+    /// The column number the warning was found on
+    pub fn get_column(&self) -> u32 {
+        return self.pos.get_column();
+    }
+
+    /// Attaches the offending line of source code to the warning,
+    /// so that it is shown beneath the message
+    ///
+    /// # Arguments
+    ///
+    /// * `new_src` - The text of the line the warning was found on
+    pub fn set_source(&mut self, new_src: &str) {
+        self.src = String::from(new_src);
+    }
+}
+
+//Debug implementation
+impl fmt::Debug for AsmWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+//Display implementation
+impl fmt::Display for AsmWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}): warning: {}", self.pos, self.msg)?;
+
+        //show the offending source line
+        if !self.src.is_empty() {
+            write!(f, "{}",
+                   mark_source(&self.src, self.pos.get_column()))?;
+        }
+        return Ok(());
+    }
+}
+
+//end of file
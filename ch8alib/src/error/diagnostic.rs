@@ -0,0 +1,138 @@
+/*
+ * diagnostic.rs
+ * Defines a struct that describes an error or warning for tools
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use serde::Serialize;
+use super::AsmError;
+use super::AsmWarning;
+
+/// A machine-readable description of an error or warning,
+/// for editors and other tools
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct Diagnostic {
+    /// The line number the problem was found on,
+    /// if it is tied to a position in the source
+    line: Option<u32>,
+
+    /// The column number the problem was found on,
+    /// if it is tied to a position in the source
+    col: Option<u32>,
+
+    /// Either `"error"` or `"warning"`
+    severity: &'static str,
+
+    /// A description of the problem, without its position
+    message: String
+}
+
+//implementation
+impl Diagnostic {
+    /// Constructs a new `Diagnostic` instance from an error
+    ///
+    /// # Argument
+    ///
+    /// * `err` - The error to describe
+    ///
+    /// # Returns
+    ///
+    /// A new `Diagnostic` instance with the severity `"error"`
+    pub fn from_error(err: &AsmError) -> Self {
+        //get the first line of the message, which comes
+        //before any source line that was attached
+        let text = err.to_string();
+        let first = text.lines().next().unwrap_or("");
+
+        //and remove the position it starts with
+        let message = match (err.get_line(), err.get_column()) {
+            (Some(l), Some(c)) => {
+                let prefix = format!("({}:{}): ", l, c);
+                first.strip_prefix(prefix.as_str()).unwrap_or(first)
+            },
+            _ => first
+        };
+
+        return Diagnostic {
+            line: err.get_line(),
+            col: err.get_column(),
+            severity: "error",
+            message: String::from(message)
+        };
+    }
+
+    /// Constructs a new `Diagnostic` instance from a warning
+    ///
+    /// # Argument
+    ///
+    /// * `warn` - The warning to describe
+    ///
+    /// # Returns
+    ///
+    /// A new `Diagnostic` instance with the severity `"warning"`
+    pub fn from_warning(warn: &AsmWarning) -> Self {
+        return Diagnostic {
+            line: Some(warn.get_line()),
+            col: Some(warn.get_column()),
+            severity: "warning",
+            message: String::from(warn.get_message())
+        };
+    }
+
+    /// Gets the line number the problem was found on
+    ///
+    /// # Returns
+    ///
+    /// The line number, or `None` if the problem
+    /// is not tied to a position in the source
+    pub fn get_line(&self) -> Option<u32> {
+        return self.line;
+    }
+
+    /// Gets the column number the problem was found on
+    ///
+    /// # Returns
+    ///
+    /// The column number, or `None` if the problem
+    /// is not tied to a position in the source
+    pub fn get_column(&self) -> Option<u32> {
+        return self.col;
+    }
+
+    /// Gets the severity of the problem
+    ///
+    /// # Returns
+    ///
+    /// Either `"error"` or `"warning"`
+    pub fn get_severity(&self) -> &str {
+        return self.severity;
+    }
+
+    /// Gets the description of the problem
+    ///
+    /// # Returns
+    ///
+    /// The description of the problem, without its position
+    pub fn get_message(&self) -> &str {
+        return &self.message;
+    }
+}
+
+//end of file
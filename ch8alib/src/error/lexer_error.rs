@@ -20,21 +20,26 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-//usage statement
+//usage statements
 use std::fmt;
+use super::super::util::mark_source;
+use super::super::util::Position;
+use super::LexerErrorType;
 
 /// Created when a `Lexer` encounters an error
 pub struct LexerError {
-    /// The line of source code that
-    /// the error was triggered on 
-    line: u32,
+    /// The position the error was triggered at
+    pos: Position,
 
-    /// The column of source code that
-    /// the error was triggered on
-    col: u32,
+    /// The source line the error was found on
+    /// (empty until attached with `set_source`)
+    src: String,
 
-    /// The character that triggered the error
-    chr: char
+    /// The text that triggered the error
+    text: String,
+
+    /// The type of the error
+    etype: LexerErrorType
 }
 
 //implementation
@@ -43,20 +48,78 @@ impl LexerError {
     ///
     /// # Arguments
     ///
-    /// * `bad_line` - The line that the error was triggered on
-    /// * `bad_col` - The column that the error was triggered on
+    /// * `bad_pos` - The position that the error was triggered at
     /// * `bad_char` - The character that triggered th error
     /// 
     /// # Returns
     ///
     /// A new `LexerError` instance with the given properties
-    pub fn new(bad_line: u32, bad_col: u32, bad_char: char) -> Self {
+    pub fn new(bad_pos: Position, bad_char: char) -> Self {
         return LexerError {
-            line: bad_line,
-            col: bad_col,
-            chr: bad_char 
+            pos: bad_pos,
+            src: String::new(),
+            text: bad_char.to_string(),
+            etype: LexerErrorType::UnknownChar
         };
     }
+
+    /// Constructs a new `LexerError` instance of a given type
+    ///
+    /// # Arguments
+    ///
+    /// * `new_type` - The type of the error
+    /// * `bad_pos` - The position that the error was triggered at
+    /// * `bad_text` - The text that triggered the error
+    ///
+    /// # Returns
+    ///
+    /// A new `LexerError` instance with the given properties
+    pub fn with_type(new_type: LexerErrorType, bad_pos: Position,
+                     bad_text: &str) -> Self {
+        return LexerError {
+            pos: bad_pos,
+            src: String::new(),
+            text: String::from(bad_text),
+            etype: new_type
+        };
+    }
+
+    /// Gets the type of the error
+    ///
+    /// # Returns
+    ///
+    /// The type of the error
+    pub fn get_type(&self) -> LexerErrorType {
+        return self.etype.clone();
+    }
+
+    /// Gets the line number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The line number the error was found on
+    pub fn get_line(&self) -> u32 {
+        return self.pos.get_line();
+    }
+
+    /// Gets the column number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The column number the error was found on
+    pub fn get_column(&self) -> u32 {
+        return self.pos.get_column();
+    }
+
+    /// Attaches the offending line of source code to the error,
+    /// so that it is shown beneath the message
+    ///
+    /// # Arguments
+    ///
+    /// * `new_src` - The text of the line the error was found on
+    pub fn set_source(&mut self, new_src: &str) {
+        self.src = String::from(new_src);
+    }
 }
 
 //Debug implementation
@@ -69,8 +132,15 @@ impl fmt::Debug for LexerError {
 //Display implementation
 impl fmt::Display for LexerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}:{}): unknown character {}", 
-               self.line, self.col, self.chr)
+        write!(f, "({}): {} {}", 
+               self.pos, self.etype, self.text)?;
+
+        //show the offending source line
+        if !self.src.is_empty() {
+            write!(f, "{}",
+                   mark_source(&self.src, self.pos.get_column()))?;
+        }
+        return Ok(());
     }
 }
 
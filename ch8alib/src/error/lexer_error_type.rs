@@ -0,0 +1,77 @@
+/*
+ * lexer_error_type.rs
+ * Enumerates types of lexer errors
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statement
+use std::fmt;
+
+/// Types of lexer errors
+#[derive(Clone, PartialEq)]
+pub enum LexerErrorType {
+    /// A character that does not start any token
+    UnknownChar,
+
+    /// A numeric literal with missing or invalid digits
+    BadLiteral,
+
+    /// A numeric literal too large to fit in a word
+    Overflow,
+
+    /// A block comment that is never closed
+    Unterminated,
+
+    /// A string literal that is not closed on its line
+    UnterminatedStr
+}
+
+//implementation
+impl LexerErrorType {
+    /// Gets a description of the error type
+    ///
+    /// # Returns
+    ///
+    /// A description of the error type
+    pub fn get_desc(&self) -> &str {
+        return match *self {
+            LexerErrorType::UnknownChar => "unknown character",
+            LexerErrorType::BadLiteral => "malformed literal",
+            LexerErrorType::Overflow => "literal out of range",
+            LexerErrorType::Unterminated => "unterminated block comment",
+            LexerErrorType::UnterminatedStr => "unterminated string"
+        };
+    }
+}
+
+//Debug implementation
+impl fmt::Debug for LexerErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.get_desc())
+    }
+}
+
+//Display implementation
+impl fmt::Display for LexerErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.get_desc())
+    }
+}
+
+//end of file
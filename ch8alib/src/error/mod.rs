@@ -23,6 +23,8 @@
 //exports
 mod lexer_error;
 pub use lexer_error::LexerError;
+mod lexer_error_type;
+pub use lexer_error_type::LexerErrorType;
 mod addr_error;
 pub use addr_error::AddrError;
 mod variant_error;
@@ -37,5 +39,25 @@ mod arg_error;
 pub use arg_error::ArgError;
 mod skip_error;
 pub use skip_error::SkipError;
+mod cond_error;
+pub use cond_error::CondError;
+mod overflow_error;
+pub use overflow_error::OverflowError;
+mod alias_error;
+pub use alias_error::AliasError;
+mod expr_error;
+pub use expr_error::ExprError;
+mod range_error;
+pub use range_error::RangeError;
+mod stray_error;
+pub use stray_error::StrayError;
+mod reg_error;
+pub use reg_error::RegError;
+mod sigil_error;
+pub use sigil_error::SigilError;
+mod asm_warning;
+pub use asm_warning::AsmWarning;
+mod diagnostic;
+pub use diagnostic::Diagnostic;
 
 //end of file
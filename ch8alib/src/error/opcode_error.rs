@@ -22,17 +22,20 @@
 
 //usage statement
 use std::fmt;
+use super::super::util::mark_source;
+use super::super::util::Position;
 
 /// Created when an instruction string doesn't map to a valid opcode
 pub struct OpcodeError {
     /// The instruction string that generated the error
     bad_instr: String,
 
-    /// The line number the instruction was found on
-    line: u32,
+    /// The position the instruction was found at
+    pos: Position,
 
-    /// The column number the instruction was found on
-    col: u32 
+    /// The source line the error was found on
+    /// (empty until attached with `set_source`)
+    src: String
 }
 
 //Implementation
@@ -42,19 +45,46 @@ impl OpcodeError {
     /// # Arguments
     ///
     /// * `new_instr` - The instruction that generated the error
-    /// * `new_line` - The line number of the bad instruction
-    /// * `new_col` - The column number of the bad instruction
+    /// * `new_pos` - The position of the bad instruction
     /// 
     /// # Returns
     ///
     /// A new `OpcodeError` instance with the given properties
-    pub fn new(new_instr: &str, new_line: u32, new_col: u32) -> Self {
+    pub fn new(new_instr: &str, new_pos: Position) -> Self {
         return OpcodeError {
             bad_instr: String::from(new_instr),
-            line: new_line,
-            col: new_col 
+            pos: new_pos,
+            src: String::new()
         };
     }
+
+    /// Gets the line number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The line number the error was found on
+    pub fn get_line(&self) -> u32 {
+        return self.pos.get_line();
+    }
+
+    /// Gets the column number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The column number the error was found on
+    pub fn get_column(&self) -> u32 {
+        return self.pos.get_column();
+    }
+
+    /// Attaches the offending line of source code to the error,
+    /// so that it is shown beneath the message
+    ///
+    /// # Arguments
+    ///
+    /// * `new_src` - The text of the line the error was found on
+    pub fn set_source(&mut self, new_src: &str) {
+        self.src = String::from(new_src);
+    }
 }
 
 //Debug implementation
@@ -67,8 +97,15 @@ impl fmt::Debug for OpcodeError {
 //Display implementation
 impl fmt::Display for OpcodeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}:{}): unknown instruction {}",
-                self.line, self.col, self.bad_instr)
+        write!(f, "({}): unknown instruction {}",
+                self.pos, self.bad_instr)?;
+
+        //show the offending source line
+        if !self.src.is_empty() {
+            write!(f, "{}",
+                   mark_source(&self.src, self.pos.get_column()))?;
+        }
+        return Ok(());
     }
 }
 
@@ -0,0 +1,75 @@
+/*
+ * overflow_error.rs
+ * Defines an error that is generated when a program
+ * does not fit in Chip-8 memory
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statement
+use std::fmt;
+
+/// Generated when a program runs past the end of Chip-8 memory
+pub struct OverflowError {
+    /// The number of bytes past the end of memory
+    over: usize
+}
+
+//implementation
+impl OverflowError {
+    /// Constructs a new `OverflowError` instance
+    ///
+    /// # Argument
+    ///
+    /// * `new_over` - The number of bytes past the end of memory
+    ///
+    /// # Returns
+    ///
+    /// A new `OverflowError` instance with the given properties
+    pub fn new(new_over: usize) -> Self {
+        return OverflowError {
+            over: new_over
+        };
+    }
+
+    /// Gets the number of bytes past the end of memory
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes the program is over the limit
+    pub fn get_over(&self) -> usize {
+        return self.over;
+    }
+}
+
+//Debug implementation
+impl fmt::Debug for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+//Display implementation
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Binary is too large ({} bytes past the end of memory)",
+               self.over)
+    }
+}
+
+//end of file
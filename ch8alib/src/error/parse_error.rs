@@ -23,6 +23,8 @@
 //usage statements
 use super::super::lex::TokenType;
 use std::fmt;
+use super::super::util::mark_source;
+use super::super::util::Position;
 
 /// Generated when a Chip-8 parser encounters an error 
 pub struct ParseError {
@@ -32,11 +34,16 @@ pub struct ParseError {
     /// The actual token
     actual: TokenType,
 
-    /// The line number of the bad symbol
-    line: u32,
+    /// The position of the bad symbol
+    pos: Position,
 
-    /// The column number of the bad symbol
-    col: u32 
+    /// The instruction being assembled when the error was found
+    /// (empty if it was not found inside an instruction)
+    instr: String,
+
+    /// The source line the error was found on
+    /// (empty until attached with `set_source`)
+    src: String
 }
 
 //implementation
@@ -47,21 +54,59 @@ impl ParseError {
     ///
     /// * `new_expected` - The expected token type
     /// * `new_actual` - The actual token type
-    /// * `new_line` - The line of code that triggered the error
-    /// * `new_col` - The column of code that triggered the error
+    /// * `new_pos` - The position of code that triggered the error
     /// 
     /// # Returns
     ///
     /// A new `ParseError` instance with the given properties
     pub fn new(new_expected: &TokenType, new_actual: &TokenType,
-               new_line: u32, new_col: u32) -> Self {
+               new_pos: Position) -> Self {
         return ParseError {
             expected: new_expected.clone(),
             actual: new_actual.clone(),
-            line: new_line,
-            col: new_col 
+            pos: new_pos,
+            instr: String::new(),
+            src: String::new()
         };
     }
+
+    /// Gets the line number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The line number the error was found on
+    pub fn get_line(&self) -> u32 {
+        return self.pos.get_line();
+    }
+
+    /// Gets the column number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The column number the error was found on
+    pub fn get_column(&self) -> u32 {
+        return self.pos.get_column();
+    }
+
+    /// Records the instruction that was being assembled
+    /// when the error was found
+    ///
+    /// # Arguments
+    ///
+    /// * `new_instr` - The mnemonic of the instruction
+    pub fn set_instruction(&mut self, new_instr: &str) {
+        self.instr = String::from(new_instr);
+    }
+
+    /// Attaches the offending line of source code to the error,
+    /// so that it is shown beneath the message
+    ///
+    /// # Arguments
+    ///
+    /// * `new_src` - The text of the line the error was found on
+    pub fn set_source(&mut self, new_src: &str) {
+        self.src = String::from(new_src);
+    }
 }
 
 //Debug implementation
@@ -74,8 +119,22 @@ impl fmt::Debug for ParseError {
 //Display implementation
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}:{}): Expected {}, found {}", 
-               self.line, self.col, self.expected, self.actual)
+        //name the instruction if there is one
+        if self.instr.is_empty() {
+            write!(f, "({}): Expected {}, found {}", 
+                   self.pos, self.expected, self.actual)?;
+        } else {
+            write!(f, "({}): while assembling {}: expected {}, found {}",
+                   self.pos, self.instr, self.expected,
+                   self.actual)?;
+        }
+
+        //show the offending source line
+        if !self.src.is_empty() {
+            write!(f, "{}",
+                   mark_source(&self.src, self.pos.get_column()))?;
+        }
+        return Ok(());
     }
 }
 
@@ -0,0 +1,80 @@
+/*
+ * sigil_error.rs
+ * Defines an error that is generated when the markers
+ * for comments and literals collide
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statement
+use std::fmt;
+
+/// Generated when a comment or literal marker cannot be told apart
+/// from another marker or from the rest of the syntax
+pub struct SigilError {
+    /// The marker that caused the error
+    marker: String,
+
+    /// Why the marker cannot be used
+    reason: String
+}
+
+//implementation
+impl SigilError {
+    /// Constructs a new `SigilError` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `new_marker` - The marker that caused the error
+    /// * `new_reason` - Why the marker cannot be used
+    ///
+    /// # Returns
+    ///
+    /// A new `SigilError` instance with the given properties
+    pub fn new(new_marker: &str, new_reason: &str) -> Self {
+        return SigilError {
+            marker: String::from(new_marker),
+            reason: String::from(new_reason)
+        };
+    }
+
+    /// Gets the marker that caused the error
+    ///
+    /// # Returns
+    ///
+    /// The marker that cannot be used
+    pub fn get_marker(&self) -> &str {
+        return &self.marker;
+    }
+}
+
+//Debug implementation
+impl fmt::Debug for SigilError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+//Display implementation
+impl fmt::Display for SigilError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid marker {}: {}", self.marker, self.reason)
+    }
+}
+
+//end of file
@@ -27,6 +27,9 @@ extern crate ch8_isa;
 //usage statements
 use ch8_isa::data::SkipType;
 use std::fmt;
+use super::super::util::mark_source;
+use super::super::util::Position;
+use super::super::util::skip_name;
 
 
 /// Generated when a `SKIP` instruction is given a bad condition
@@ -34,11 +37,12 @@ pub struct SkipError {
     /// The `SkipType` that caused the error 
     bad_type: SkipType,
 
-    /// The line number that the error was found on
-    line: u32,
+    /// The position the error was found at
+    pos: Position,
 
-    /// The column the error was found on
-    col: u32 
+    /// The source line the error was found on
+    /// (empty until attached with `set_source`)
+    src: String
 }
 
 //Implementation
@@ -48,19 +52,46 @@ impl SkipError {
     /// # Arguments
     ///
     /// * `new_type` - The `SkipType` that triggered the error
-    /// * `new_line` - The line number the error was found on
-    /// * `new_col` - The column number the error was found on
+    /// * `new_pos` - The position the error was found at
     /// 
     /// # Returns
     ///
     /// A new `SkipError` instance with the given properties
-    pub fn new(new_type: SkipType, new_line: u32, new_col: u32) -> Self {
+    pub fn new(new_type: SkipType, new_pos: Position) -> Self {
         return SkipError {
             bad_type: new_type,
-            line: new_line,
-            col: new_col 
+            pos: new_pos,
+            src: String::new()
         };
     }
+
+    /// Gets the line number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The line number the error was found on
+    pub fn get_line(&self) -> u32 {
+        return self.pos.get_line();
+    }
+
+    /// Gets the column number the error was found on
+    ///
+    /// # Returns
+    ///
+    /// The column number the error was found on
+    pub fn get_column(&self) -> u32 {
+        return self.pos.get_column();
+    }
+
+    /// Attaches the offending line of source code to the error,
+    /// so that it is shown beneath the message
+    ///
+    /// # Arguments
+    ///
+    /// * `new_src` - The text of the line the error was found on
+    pub fn set_source(&mut self, new_src: &str) {
+        self.src = String::from(new_src);
+    }
 }
 
 //Debug implementation
@@ -73,8 +104,15 @@ impl fmt::Debug for SkipError {
 //Display implementation
 impl fmt::Display for SkipError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}:{}): Bad skip type {:?}",
-                self.line, self.col, self.bad_type)
+        write!(f, "({}): Bad skip type {}",
+                self.pos, skip_name(&self.bad_type))?;
+
+        //show the offending source line
+        if !self.src.is_empty() {
+            write!(f, "{}",
+                   mark_source(&self.src, self.pos.get_column()))?;
+        }
+        return Ok(());
     }
 }
 
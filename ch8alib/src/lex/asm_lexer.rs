@@ -24,17 +24,26 @@
 use super::super::util::constants;
 use super::Token;
 use super::TokenType;
+use super::Tokens;
 use super::super::error::LexerError;
+use super::super::error::LexerErrorType;
 use super::super::util::Variant;
+use super::super::util::parse_lit;
+use super::super::util::parse_dec_lit;
+use super::super::util::Position;
+use super::super::util::Sigils;
 
 /// Lexes Chip-8 assembly code
 pub struct AsmLexer {
     /// The code being lexed
-    text: String,
+    text: Vec<char>,
 
     /// The current position in the text
     pos: usize,
 
+    /// The byte offset of the current position in the text
+    byte_pos: usize,
+
     /// The current character being lexed
     cur_char: char,
 
@@ -42,7 +51,22 @@ pub struct AsmLexer {
     line: u32,
 
     /// The current column being lexed
-    col: u32 
+    col: u32,
+
+    /// The line the most recent token started on
+    tok_line: u32,
+
+    /// The column the most recent token started on
+    tok_col: u32,
+
+    /// The byte offset the most recent token started at
+    tok_start: usize,
+
+    /// The position in the text that each line starts at
+    line_starts: Vec<usize>,
+
+    /// The markers that start comments and literals
+    sigils: Sigils
 }
 
 //implementation
@@ -57,12 +81,46 @@ impl AsmLexer {
     ///
     /// A new `AsmLexer` instance with the given text
     pub fn new(new_text: &str) -> Self {
+        return AsmLexer::with_sigils(new_text, &Sigils::new());
+    }
+
+    /// Constructs a new `AsmLexer` instance that uses
+    /// the given comment and literal markers
+    ///
+    /// # Arguments
+    ///
+    /// * `new_text` - The text to lex
+    /// * `sigils` - The markers that start comments and literals
+    ///
+    /// # Returns
+    ///
+    /// A new `AsmLexer` instance with the given properties
+    pub fn with_sigils(new_text: &str, sigils: &Sigils) -> Self {
+        //collect the characters of the text
+        //(empty text starts out at the end of input)
+        let chars: Vec<char> = new_text.chars().collect();
+
+        //find where each line starts
+        let mut starts = vec![0];
+        for (i, c) in chars.iter().enumerate() {
+            if *c == '\n' {
+                starts.push(i + 1);
+            }
+        }
+
+        //and return the instance
         return AsmLexer {
-            text: String::from(new_text),
+            cur_char: *chars.first().unwrap_or(&'\0'),
+            text: chars,
             pos: 0,
-            cur_char: new_text.chars().nth(0).unwrap(),
+            byte_pos: 0,
             line: 1,
-            col: 1
+            col: 1,
+            tok_line: 1,
+            tok_col: 1,
+            tok_start: 0,
+            line_starts: starts,
+            sigils: sigils.clone()
         };
     }
 
@@ -84,15 +142,99 @@ impl AsmLexer {
         return self.col;
     }
 
+    /// Gets the current position being lexed
+    ///
+    /// # Returns
+    ///
+    /// The current line and column being lexed
+    pub fn get_position(&self) -> Position {
+        return Position::new(self.line, self.col);
+    }
+
+    /// Gets the line the most recent token started on
+    ///
+    /// # Returns
+    ///
+    /// The line number the most recent token started on
+    pub fn get_token_line(&self) -> u32 {
+        return self.tok_line;
+    }
+
+    /// Gets the column the most recent token started on
+    ///
+    /// # Returns
+    ///
+    /// The column number the most recent token started on
+    pub fn get_token_column(&self) -> u32 {
+        return self.tok_col;
+    }
+
+    /// Gets the text of a line of the source code
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The number of the line to get (starting at 1)
+    ///
+    /// # Returns
+    ///
+    /// The text of the line, without its line ending,
+    /// or an empty string if the line does not exist
+    pub fn get_line_text(&self, line: u32) -> String {
+        //get the bounds of the line
+        let idx = (line as usize).saturating_sub(1);
+        let start = match self.line_starts.get(idx) {
+            Some(st) => *st,
+            None => return String::new()
+        };
+        let end = match self.line_starts.get(idx + 1) {
+            Some(st) => *st - 1,
+            None => self.text.len()
+        };
+
+        //and collect its text
+        let text: String = self.text[start..end].iter().collect();
+        return String::from(text.trim_end_matches('\r'));
+    }
+
     /// Gets the next `Token` consumed from the input
     ///
+    /// The returned `Token` carries the span of
+    /// source text it was lexed from
+    ///
     /// # Returns
     ///
     /// The next `Token` consumed from the input,
     /// wrapped in a `Result`
     pub fn get_next_token(&mut self) -> Result<Token, LexerError> {
+        let tok = self.lex_token()?;
+        return Ok(tok.with_span(self.tok_start, self.byte_pos));
+    }
+
+    /// Iterates over the remaining tokens in the input
+    ///
+    /// The iterator ends after yielding the end of input token
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding each `Token`, wrapped in a `Result`
+    pub fn tokens(&mut self) -> Tokens<'_> {
+        return Tokens::new(self);
+    }
+
+    /// Lexes the next `Token` from the input
+    ///
+    /// # Returns
+    ///
+    /// The next `Token` consumed from the input,
+    /// wrapped in a `Result`
+    fn lex_token(&mut self) -> Result<Token, LexerError> {
         //loop and lex the text
         while self.cur_char != '\0' {
+            //mark where the token starts
+            self.tok_line = self.line;
+            self.tok_col = self.col;
+            self.tok_start = self.byte_pos;
+
             //handle whitespace
             if self.cur_char.is_ascii_whitespace() {
                 self.skip_whitespace();
@@ -107,27 +249,64 @@ impl AsmLexer {
             }
 
             //handle periods
-            if self.cur_char == '.' {
+            if (self.cur_char == '.') && !self.at_local_label() {
                 self.advance();
                 return Ok(Token::new(TokenType::Period,
                                      Variant::Text(String::from("."))));
             }
 
-            //handle comments
-            if self.cur_char == constants::COMMENT_CHAR {
-                self.consume_comment();
+            //handle string literals (before comments, since
+            //a string may hold a comment marker)
+            if self.cur_char == constants::STRING_QUOTE_CHAR {
+                return Ok(Token::new(TokenType::StrLit,
+                                     Variant::Text(self.str_lit()?)));
+            }
+
+            //handle comments (before operators, since
+            //a comment marker such as `//` may look like one)
+            if self.at_comment() {
+                self.consume_comment()?;
                 continue;
             }
 
+            //handle arithmetic operators and parentheses
+            let op_type = match self.cur_char {
+                '+' => Some(TokenType::Plus),
+                '-' => Some(TokenType::Minus),
+                '*' => Some(TokenType::Asterisk),
+                '/' => Some(TokenType::Slash),
+                '(' => Some(TokenType::LeftParen),
+                ')' => Some(TokenType::RightParen),
+                _ => None
+            };
+            if let Some(ttype) = op_type {
+                let text = self.cur_char.to_string();
+                self.advance();
+                return Ok(Token::new(ttype, Variant::Text(text)));
+            }
+
+            //handle line continuations, which are skipped
+            //like whitespace so the statement carries on
+            if self.at_continuation() {
+                self.advance();
+                continue;
+            }
+
+            //handle statement separators
+            if self.cur_char == constants::SEPARATOR_CHAR {
+                self.advance();
+                return Ok(Token::new(TokenType::Separator,
+                        Variant::Text(constants::SEPARATOR_CHAR.to_string())));
+            }
+
             //handle register references
-            if (self.cur_char.to_ascii_uppercase() == 'V') ||
-                (self.cur_char.to_ascii_uppercase() == 'I') {
+            if self.at_register() {
                 return Ok(Token::new(TokenType::Register,
                                      Variant::Text(self.register())));
             }
 
             //handle labels
-            if self.cur_char == '_' {
+            if (self.cur_char == '_') || self.at_local_label() {
                 //get the label text
                 let lbl = self.label();
 
@@ -137,6 +316,9 @@ impl AsmLexer {
                 if last == ':' {
                     return Ok(Token::new(TokenType::LblDef,
                             Variant::Text(String::from(&lbl[0..lidx]))));
+                } else if is_section(&lbl) {
+                    return Ok(Token::new(TokenType::Section,
+                            Variant::Text(lbl[1..].to_ascii_lowercase())));
                 } else {
                     return Ok(Token::new(TokenType::Label,
                                     Variant::Text(lbl)));
@@ -145,14 +327,30 @@ impl AsmLexer {
 
             //handle symbols
             if self.cur_char.is_ascii_alphabetic() {
+                //a symbol right after the period in `SKIP.EQ`
+                //is always a skip condition, even a misspelled one
+                let after_period = (self.pos > 0) &&
+                                    (self.text[self.pos - 1] == '.');
+
                 //get the symbol
                 let sym = self.symbol();
 
-                //check to see if it's a skiptype
-                if ((sym == "EQ") || (sym == "NE")) ||
-                    ((sym == "KD") || (sym == "KU")) {
+                //check to see if it names a register alias
+                if self.at_alias_keyword() {
+                    self.skip_whitespace();
+                    self.symbol();
+                    return Ok(Token::new(TokenType::RegDef,
+                                    Variant::Text(sym)));
+                }
+
+                //check to see if it's a skiptype or a timer
+                if after_period ||
+                    constants::SKIP_CONDS.contains(&sym.as_str()) {
                     return Ok(Token::new(TokenType::SkipCond,
                                     Variant::Text(sym)));
+                } else if constants::TIMERS.contains(&sym.as_str()) {
+                    return Ok(Token::new(TokenType::Timer,
+                                    Variant::Text(sym)));
                 } else {
                     return Ok(Token::new(TokenType::Instruction,
                                     Variant::Text(sym)));
@@ -160,53 +358,227 @@ impl AsmLexer {
             }
 
             //handle decimal literals
-            if self.cur_char == constants::DEC_LIT_CHAR {
-                return Ok(Token::new(TokenType::DecLit,
-                                     Variant::Word(self.dec_lit())));
+            if (self.cur_char == self.sigils.get_dec()) ||
+                self.at_prefix(constants::DEC_LIT_PREFIX) {
+                return self.dec_lit();
             }
 
             //handle hex literals
-            if self.cur_char == constants::HEX_LIT_CHAR {
+            if (self.cur_char == self.sigils.get_hex()) ||
+                self.at_prefix(constants::HEX_LIT_PREFIX) {
                 return Ok(Token::new(TokenType::HexLit,
-                                     Variant::Word(self.hex_lit())));
+                                     Variant::Word(self.hex_lit()?)));
             }
 
             //handle binary literals
-            if self.cur_char == constants::BIN_LIT_CHAR {
-                return Ok(Token::new(TokenType::BinLit,
-                                     Variant::Byte(self.bin_lit())));
+            if (self.cur_char == self.sigils.get_bin()) ||
+                self.at_prefix(constants::BIN_LIT_PREFIX) {
+                return Ok(Token::new(TokenType::BinLit, self.bin_lit()?));
             }
 
             //if control reaches here, then
             //an unknown character was found
-            //so we return an error
-            return Err(LexerError::new(self.line, self.col,
-                                       self.cur_char));
+            //so we skip it and return an error
+            let err = LexerError::new(self.get_position(), self.cur_char);
+            self.advance();
+            return Err(err);
         }
 
         //if control reaches here, then
         //the end of input was found
+        self.tok_line = self.line;
+        self.tok_col = self.col;
+        self.tok_start = self.byte_pos;
         return Ok(Token::new(TokenType::EndOfInput,
                             Variant::Text(String::from(""))));
     }
 
     /// Advances the lexer to the next character
-    ///
-    /// # Panics
-    ///
-    /// This method will panic if the next character
-    /// cannot be retrieved from the text.
     fn advance(&mut self) {
+        //update the byte offset
+        if self.pos < self.text.len() {
+            self.byte_pos += self.text[self.pos].len_utf8();
+        }
+
         //update the position
         self.pos += 1;
 
         //and get the next character
-        if self.pos > (self.text.len() - 1) {
+        if self.pos >= self.text.len() {
             self.cur_char = '\0';
         } else {
             self.col += 1;
-            self.cur_char = self.text.chars().nth(self.pos).unwrap();
+            self.cur_char = self.text[self.pos];
+        }
+    }
+
+    /// Gets the character after the current one without consuming it
+    ///
+    /// # Returns
+    ///
+    /// The next character in the text, or `'\0'` at the end of input
+    fn peek(&self) -> char {
+        return match self.text.get(self.pos + 1) {
+            Some(c) => *c,
+            None => '\0'
+        };
+    }
+
+    /// Gets the character a given distance after the current one
+    /// without consuming it
+    ///
+    /// # Argument
+    ///
+    /// * `dist` - How many characters ahead to look
+    ///
+    /// # Returns
+    ///
+    /// The character at that distance, or `'\0'` at the end of input
+    fn peek_at(&self, dist: usize) -> char {
+        return match self.text.get(self.pos + dist) {
+            Some(c) => *c,
+            None => '\0'
+        };
+    }
+
+    /// Determines whether the current character starts a register
+    /// reference, rather than a symbol that begins with `V` or `I`
+    ///
+    /// # Returns
+    ///
+    /// Whether a register reference starts at the current position
+    fn at_register(&self) -> bool {
+        if self.cur_char.eq_ignore_ascii_case(&'I') {
+            return !is_ident_char(self.peek());
+        } else if self.cur_char.eq_ignore_ascii_case(&'V') {
+            return self.peek().is_ascii_hexdigit() &&
+                    !is_ident_char(self.peek_at(2));
+        } else {
+            return false;
+        }
+    }
+
+    /// Determines whether the next word on the line is
+    /// the keyword that defines a register alias
+    ///
+    /// # Returns
+    ///
+    /// Whether the alias keyword follows the current position
+    fn at_alias_keyword(&self) -> bool {
+        //skip the spaces before the word
+        let mut i = self.pos;
+        while (i < self.text.len()) &&
+            ((self.text[i] == ' ') || (self.text[i] == '\t')) {
+            i += 1;
+        }
+
+        //collect the word
+        let mut word = String::new();
+        while (i < self.text.len()) && is_ident_char(self.text[i]) {
+            word.push(self.text[i].to_ascii_uppercase());
+            i += 1;
         }
+
+        //and compare it to the keyword
+        return word == constants::ALIAS_KEYWORD;
+    }
+
+    /// Determines whether a local label starts at the current position
+    ///
+    /// # Returns
+    ///
+    /// Whether the current character starts a local label
+    fn at_local_label(&self) -> bool {
+        //local labels start a new word, unlike the
+        //period that follows `SKIP` in `SKIP.EQ`
+        let after_word = (self.pos > 0) &&
+                            is_ident_char(self.text[self.pos - 1]);
+        return (self.cur_char == constants::LOCAL_LABEL_CHAR) &&
+                !after_word &&
+                (self.peek().is_ascii_alphabetic() || (self.peek() == '_'));
+    }
+
+    /// Determines whether the current character starts a
+    /// C-style literal prefix (such as `0x` or `0b`)
+    ///
+    /// # Argument
+    ///
+    /// * `letter` - The letter that follows the zero in the prefix
+    ///
+    /// # Returns
+    ///
+    /// Whether the text at the current position starts with the prefix
+    fn at_prefix(&self, letter: char) -> bool {
+        return (self.cur_char == '0') &&
+                self.peek().eq_ignore_ascii_case(&letter);
+    }
+
+    /// Advances past the sigil or C-style prefix that starts a literal
+    ///
+    /// # Argument
+    ///
+    /// * `letter` - The letter that follows the zero in the prefix
+    ///
+    /// # Returns
+    ///
+    /// The sigil or prefix, as it was written
+    fn lit_prefix(&mut self, letter: char) -> String {
+        let mut ret = String::new();
+        if self.at_prefix(letter) {
+            ret.push(self.cur_char);
+            self.advance();
+        }
+        ret.push(self.cur_char);
+        self.advance();
+        return ret;
+    }
+
+    /// Determines whether a comment marker starts at the
+    /// current position
+    ///
+    /// # Returns
+    ///
+    /// Whether the text at the current position starts a comment
+    fn at_comment(&self) -> bool {
+        return self.comment_at(0);
+    }
+
+    /// Determines whether a comment marker starts a given
+    /// number of characters ahead of the current position
+    ///
+    /// # Argument
+    ///
+    /// * `dist` - How many characters ahead to look
+    ///
+    /// # Returns
+    ///
+    /// Whether the text at that distance starts a comment
+    fn comment_at(&self, dist: usize) -> bool {
+        return self.sigils.get_comment().chars().enumerate()
+                    .all(|(i, c)| self.peek_at(dist + i) == c);
+    }
+
+    /// Determines whether the current character is a backslash that
+    /// continues the statement on the next line, which is the case
+    /// when only blanks or a comment follow it on its line
+    ///
+    /// # Returns
+    ///
+    /// Whether a line continuation starts at the current position
+    fn at_continuation(&self) -> bool {
+        if self.cur_char != constants::SEPARATOR_CHAR {
+            return false;
+        }
+
+        //skip the blanks after the backslash
+        let mut dist = 1;
+        while [' ', '\t'].contains(&self.peek_at(dist)) {
+            dist += 1;
+        }
+
+        //and check for the end of the line
+        let next = self.peek_at(dist);
+        return ['\n', '\r', '\0'].contains(&next) || self.comment_at(dist);
     }
 
     /// Skips whitespace in the text
@@ -222,10 +594,13 @@ impl AsmLexer {
                 break;
             }
 
-            //check for newlines
-            if (self.cur_char == '\n') || (self.cur_char == '\r') {
+            //check for newlines (a CRLF pair counts as one line
+            //break, and the column is reset so that the first
+            //character of the next line lands in column 1)
+            if (self.cur_char == '\n') ||
+                ((self.cur_char == '\r') && (self.peek() != '\n')) {
                 self.line += 1;
-                self.col = 1;
+                self.col = 0;
             }
 
             //and advance the lexer
@@ -234,14 +609,61 @@ impl AsmLexer {
     }
 
     /// Consumes a comment in the text
-    fn consume_comment(&mut self) {
-        self.advance(); //advance past the semicolon
+    ///
+    /// A comment marker followed by `{` starts a block comment,
+    /// which continues until the next comment marker followed by `}`
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the comment was consumed, or `Err(LexerError)`
+    /// if a block comment is never closed
+    fn consume_comment(&mut self) -> Result<(), LexerError> {
+        //save the position of the comment
+        let pos = self.get_position();
+
+        //advance past the comment marker
+        let len = self.sigils.get_comment().chars().count();
+        for _ in 0..len {
+            self.advance();
+        }
 
-        //and consume to the end of the line
+        //handle block comments
+        if self.cur_char == constants::BLOCK_OPEN_CHAR {
+            self.advance();
+            loop {
+                //check for a missing end of the comment
+                if self.cur_char == '\0' {
+                    let text = format!("{}{}", self.sigils.get_comment(),
+                                       constants::BLOCK_OPEN_CHAR);
+                    return Err(LexerError::with_type(
+                                    LexerErrorType::Unterminated,
+                                    pos, &text));
+                }
+
+                //check for the end of the comment
+                if self.at_comment() &&
+                    (self.peek_at(len) == constants::BLOCK_CLOSE_CHAR) {
+                    for _ in 0..=len {
+                        self.advance();
+                    }
+                    return Ok(());
+                }
+
+                //and skip the character, counting line breaks
+                if self.cur_char.is_ascii_whitespace() {
+                    self.skip_whitespace();
+                } else {
+                    self.advance();
+                }
+            }
+        }
+
+        //otherwise consume to the end of the line
         while (self.cur_char != '\n') && (self.cur_char != '\r')
                 && (self.cur_char != '\0') {
             self.advance();
         }
+        return Ok(());
     }
 
     /// Lexes a text symbol in the input
@@ -254,8 +676,7 @@ impl AsmLexer {
         let mut ret = String::new();
         
         //loop and collect the symbol
-        while self.cur_char.is_ascii_alphabetic() &&
-                (self.cur_char != '\0') {
+        while is_ident_char(self.cur_char) {
             ret.push(self.cur_char.to_ascii_uppercase());
             self.advance();
         }
@@ -273,15 +694,15 @@ impl AsmLexer {
         //create the return value
         let mut ret = String::new();
 
-        //append the underscore
-        //and advance past it
-        ret.push('_');
+        //append the underscore (or the period of a
+        //local label) and advance past it
+        ret.push(self.cur_char);
         self.advance();
 
         //loop and collect the label
-        while self.cur_char.is_ascii_alphabetic() &&
-                (self.cur_char != '\0') {
-            ret.push(self.cur_char.to_ascii_uppercase());
+        while self.cur_char.is_ascii_alphanumeric() ||
+                (self.cur_char == '_') {
+            ret.push(self.cur_char);
             self.advance();
         }
 
@@ -301,7 +722,7 @@ impl AsmLexer {
     ///
     /// The register consumed from the input
     fn register(&mut self) -> String {
-        if self.cur_char == 'I' {
+        if self.cur_char.eq_ignore_ascii_case(&'I') {
             self.advance();
             return String::from("I");
         } else {
@@ -313,38 +734,113 @@ impl AsmLexer {
         }
     }
 
+    /// Lexes a string literal in the text
+    ///
+    /// Each character is written as one byte, so only ASCII
+    /// characters are allowed.
+    ///
+    /// # Returns
+    ///
+    /// The characters between the quotes, or `Err(LexerError)` if
+    /// the string is not closed on its line or holds a character
+    /// that is not ASCII
+    fn str_lit(&mut self) -> Result<String, LexerError> {
+        //save the position of the literal
+        let pos = self.get_position();
+
+        //advance past the opening quote
+        self.advance();
+
+        //loop and collect the characters up to the closing quote
+        let mut buf = String::new();
+        while self.cur_char != constants::STRING_QUOTE_CHAR {
+            //check for a missing closing quote
+            if (self.cur_char == '\0') || (self.cur_char == '\n') ||
+                (self.cur_char == '\r') {
+                let text = format!("{}{}", constants::STRING_QUOTE_CHAR,
+                                   buf);
+                return Err(LexerError::with_type(
+                                LexerErrorType::UnterminatedStr,
+                                pos, &text));
+            }
+
+            //only ASCII characters can be written as bytes
+            if !self.cur_char.is_ascii() {
+                let err = LexerError::new(self.get_position(),
+                                          self.cur_char);
+
+                //skip the rest of the string so that it
+                //is not lexed again as code
+                while (self.cur_char != constants::STRING_QUOTE_CHAR) &&
+                    (self.cur_char != '\0') && (self.cur_char != '\n') &&
+                    (self.cur_char != '\r') {
+                    self.advance();
+                }
+                if self.cur_char == constants::STRING_QUOTE_CHAR {
+                    self.advance();
+                }
+
+                return Err(err);
+            }
+
+            buf.push(self.cur_char);
+            self.advance();
+        }
+
+        //and advance past the closing quote
+        self.advance();
+        return Ok(buf);
+    }
+
     /// Lexes a decimal literal in the text
     /// 
     /// # Returns
     ///
-    /// The integer consumed from the text 
-    fn dec_lit(&mut self) -> u16 {
-        //advance past the hash sign
-        self.advance();
+    /// A token holding the integer consumed from the text (a `NegLit`
+    /// holding its 16-bit two's complement if it is negative),
+    /// wrapped in a `Result`
+    fn dec_lit(&mut self) -> Result<Token, LexerError> {
+        //save the position of the literal
+        let pos = self.get_position();
+
+        //advance past the hash sign (or the 0d prefix),
+        //keeping it for errors
+        let prefix = self.lit_prefix(constants::DEC_LIT_PREFIX);
 
         //loop and generate the integer string
         let mut buf = String::new();
+        if self.cur_char == constants::NEG_LIT_CHAR {
+            buf.push(self.cur_char);
+            self.advance();
+        }
         while self.cur_char.is_ascii_digit()
             && (self.cur_char != '\0') {
             buf.push(self.cur_char);
             self.advance();
         }
 
-        //convert the string to an integer
-        let ret = u16::from_str_radix(&buf, 10).unwrap();
-
-        //and return it
-        return ret;
+        //and convert the string to an integer
+        let num = parse_dec_lit(&prefix, &buf, pos)?;
+        let ttype = if num < 0 {
+            TokenType::NegLit
+        } else {
+            TokenType::DecLit
+        };
+        return Ok(Token::new(ttype, Variant::Word(num as u16)));
     }
 
     /// Lexes a hexadecimal literal in the text
     /// 
     /// # Returns
     ///
-    /// The integer consumed from the text 
-    fn hex_lit(&mut self) -> u16 {
-        //advance past the hash sign
-        self.advance();
+    /// The integer consumed from the text, wrapped in a `Result`
+    fn hex_lit(&mut self) -> Result<u16, LexerError> {
+        //save the position of the literal
+        let pos = self.get_position();
+
+        //advance past the dollar sign (or the 0x prefix),
+        //keeping it for errors
+        let prefix = self.lit_prefix(constants::HEX_LIT_PREFIX);
 
         //loop and generate the integer string
         let mut buf = String::new();
@@ -354,44 +850,79 @@ impl AsmLexer {
             self.advance();
         }
 
-        //convert the string to an integer
-        let ret = u16::from_str_radix(&buf, 16).unwrap();
-
-        //and return it
-        return ret;
+        //and convert the string to an integer
+        return parse_lit(&prefix, &buf, 16, pos);
     }
  
-    /// Lexes a binary literal in the text 
+    /// Lexes a binary literal in the text
+    ///
+    /// Literals with up to eight digits are bytes,
+    /// while longer literals are words
     ///
     /// # Returns
     ///
-    /// The byte consumed from the text 
-    fn bin_lit(&mut self) -> u8 {
-        //advance past the percent sign
-        self.advance();
+    /// The value consumed from the text, wrapped in a `Result`
+    fn bin_lit(&mut self) -> Result<Variant, LexerError> {
+        //save the position of the literal
+        let pos = self.get_position();
 
-        //declare the buffer
-        let mut buf = String::new();
+        //advance past the percent sign (or the 0b prefix),
+        //keeping it for errors
+        let prefix = self.lit_prefix(constants::BIN_LIT_PREFIX);
 
-        //loop and generate the string
-        for _i in 0..8 {
+        //loop and collect the digits
+        let mut buf = String::new();
+        while (self.cur_char == '0') || (self.cur_char == '1') {
             buf.push(self.cur_char);
             self.advance();
         }
 
-        //convert it to a byte
-        let ret = u8::from_str_radix(&buf, 2).unwrap();
+        //convert the digits to an integer
+        let ret = parse_lit(&prefix, &buf, 2, pos)?;
 
-        //and return the byte
-        return ret;
+        //and size it by the number of digits
+        if buf.len() > constants::MAX_BYTE_BITS {
+            return Ok(Variant::Word(ret));
+        } else {
+            return Ok(Variant::Byte(ret as u8));
+        }
     }
 }
 
+/// Determines whether a character can appear in a symbol
+///
+/// # Argument
+///
+/// * `c` - The character to check
+///
+/// # Returns
+///
+/// Whether the character can appear in a symbol
+fn is_ident_char(c: char) -> bool {
+    return c.is_ascii_alphanumeric() || (c == '_');
+}
+
+/// Determines whether a label is a section marker
+///
+/// # Argument
+///
+/// * `lbl` - The label to check, including its period
+///
+/// # Returns
+///
+/// Whether the label names a section, such as `.data`
+fn is_section(lbl: &str) -> bool {
+    return lbl.starts_with(constants::LOCAL_LABEL_CHAR) &&
+            constants::SECTIONS.iter()
+                .any(|name| lbl[1..].eq_ignore_ascii_case(name));
+}
+
 //unit tests
 #[cfg(test)]
 mod tests {
     //import the struct
     use super::*;
+    use std::time::Instant;
 
     //define code strings to be lexed
     const LEX_STR: &str = "MOV V1, V2
@@ -436,26 +967,26 @@ mod tests {
         assert_eq!(tok.get_value(), Variant::Text(String::from("V2")));
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::LblDef);
-        assert_eq!(tok.get_value(), Variant::Text(String::from("_HEX")));
+        assert_eq!(tok.get_value(), Variant::Text(String::from("_hex")));
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::HexLit);
         assert_eq!(tok.get_value(), Variant::Word(0xFC00));
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::LblDef);
-        assert_eq!(tok.get_value(), Variant::Text(String::from("_BIN")));
+        assert_eq!(tok.get_value(), Variant::Text(String::from("_bin")));
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::BinLit);
         assert_eq!(tok.get_value(), Variant::Byte(0xFF));
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::LblDef);
-        assert_eq!(tok.get_value(), Variant::Text(String::from("_DEC")));
+        assert_eq!(tok.get_value(), Variant::Text(String::from("_dec")));
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::DecLit);
         assert_eq!(tok.get_value(), Variant::Word(212));
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::LblDef);
         assert_eq!(tok.get_value(), Variant::Text(
-                                        String::from("_START")));
+                                        String::from("_start")));
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::Instruction);
         assert_eq!(tok.get_value(), Variant::Text(String::from("CLS")));
@@ -481,13 +1012,356 @@ mod tests {
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::Label);
         assert_eq!(tok.get_value(), Variant::Text(
-                                            String::from("_START")));
+                                            String::from("_start")));
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::Instruction);
         assert_eq!(tok.get_value(), Variant::Text(String::from("RET")));
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::EndOfInput);
     }
+    //this test checks error positions across multiple lines
+    #[test]
+    fn test_err_position() {
+        let mut lex = AsmLexer::new("CLS\r\nMOV V1, V2\n  MOV V1, @");
+        let mut res = lex.get_next_token();
+        while res.is_ok() {
+            res = lex.get_next_token();
+        }
+        let err = res.err().unwrap();
+        assert_eq!(format!("{}", err), "(3:11): unknown character @");
+    }
+
+    //this test checks that a decimal literal without digits is an error
+    #[test]
+    fn test_empty_dec_lit() {
+        let mut lex = AsmLexer::new("MOV V1, #\nCLS");
+        let mut res = lex.get_next_token();
+        while res.is_ok() {
+            res = lex.get_next_token();
+        }
+        let err = res.err().unwrap();
+        assert!(err.get_type() == LexerErrorType::BadLiteral);
+        assert_eq!(format!("{}", err), "(1:9): malformed literal #");
+    }
+
+    //this test checks that an oversized decimal literal is an error
+    #[test]
+    fn test_dec_lit_overflow() {
+        let mut lex = AsmLexer::new("MOV I, #70000");
+        lex.get_next_token().unwrap();
+        lex.get_next_token().unwrap();
+        lex.get_next_token().unwrap();
+        let err = lex.get_next_token().err().unwrap();
+        assert!(err.get_type() == LexerErrorType::Overflow);
+        assert_eq!(format!("{}", err), "(1:8): literal out of range #70000");
+    }
+
+    //this test checks lexing negative decimal literals
+    #[test]
+    fn test_negative_dec_lits() {
+        let mut lex = AsmLexer::new("#-1 #-128 #-32768 #5-#1");
+        let mut vals = Vec::new();
+        let mut types = Vec::new();
+        loop {
+            let tok = lex.get_next_token().unwrap();
+            if tok.get_type() == TokenType::EndOfInput {
+                break;
+            }
+            vals.push(format!("{}", tok.get_value_ref()));
+            types.push(tok.get_type());
+        }
+        assert_eq!(vals, vec!["$FFFF", "$FF80", "$8000",
+                              "$0005", "-", "$0001"]);
+        assert_eq!(types, vec![TokenType::NegLit, TokenType::NegLit,
+                               TokenType::NegLit, TokenType::DecLit,
+                               TokenType::Minus, TokenType::DecLit]);
+        let err = AsmLexer::new("#-32769").get_next_token().err().unwrap();
+        assert_eq!(format!("{}", err),
+                   "(1:1): literal out of range #-32769");
+    }
+
+    //this test checks that symbols and registers are uppercased
+    #[test]
+    fn test_mixed_case() {
+        let mut lex = AsmLexer::new("cls Cls CLS mov v0, i skip.eq");
+        let text: Vec<String> = lex.tokens()
+                            .map(|t| t.unwrap())
+                            .filter(|t| t.get_type() != TokenType::Comma &&
+                                        t.get_type() != TokenType::Period &&
+                                        t.get_type() != TokenType::EndOfInput)
+                            .map(|t| t.get_value_ref().as_text().unwrap())
+                            .collect();
+        assert_eq!(text, vec!["CLS", "CLS", "CLS", "MOV", "V0", "I",
+                              "SKIP", "EQ"]);
+    }
+
+    //this test checks skipping block comments
+    #[test]
+    fn test_block_comments() {
+        let mut lex = AsmLexer::new("CLS ;{ MOV V0, #1\n\
+                                     ; JMP _x ;}RET ;plain\nEXIT\n;{ open");
+        let mut tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_value(), Variant::Text(String::from("CLS")));
+        tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_value(), Variant::Text(String::from("RET")));
+        assert_eq!(lex.get_token_line(), 2);
+        tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_value(), Variant::Text(String::from("EXIT")));
+        assert_eq!(lex.get_token_line(), 3);
+        let err = lex.get_next_token().err().unwrap();
+        assert!(err.get_type() == LexerErrorType::Unterminated);
+        assert_eq!(format!("{}", err), "(4:1): unterminated block comment ;{");
+    }
+
+    //this test checks lexing timer names
+    #[test]
+    fn test_timers() {
+        let mut lex = AsmLexer::new("MOV delay, V0\nMOV V1, Sound");
+        let types: Vec<TokenType> = lex.tokens()
+                                       .map(|t| t.unwrap().get_type())
+                                       .collect();
+        assert_eq!(types, vec![TokenType::Instruction, TokenType::Timer,
+                               TokenType::Comma, TokenType::Register,
+                               TokenType::Instruction, TokenType::Register,
+                               TokenType::Comma, TokenType::Timer,
+                               TokenType::EndOfInput]);
+    }
+
+    //this test checks lexing local labels
+    #[test]
+    fn test_local_labels() {
+        let mut lex = AsmLexer::new(".loop: SKIP.EQ V0, #1\nJMP .loop");
+        let toks: Vec<Token> = lex.tokens().map(|t| t.unwrap()).collect();
+        assert_eq!(toks[0], Token::new(TokenType::LblDef,
+                                       Variant::Text(String::from(".loop"))));
+        assert_eq!(toks[2].get_type(), TokenType::Period);
+        assert_eq!(toks[8], Token::new(TokenType::Label,
+                                       Variant::Text(String::from(".loop"))));
+    }
+
+    //this test checks lexing arithmetic operators
+    #[test]
+    fn test_operators() {
+        let mut lex = AsmLexer::new("(#1+#2)*$3-%1/#4");
+        let types: Vec<TokenType> = lex.tokens()
+                                        .map(|t| t.unwrap().get_type())
+                                        .collect();
+        assert_eq!(types, vec![TokenType::LeftParen, TokenType::DecLit,
+                               TokenType::Plus, TokenType::DecLit,
+                               TokenType::RightParen, TokenType::Asterisk,
+                               TokenType::HexLit, TokenType::Minus,
+                               TokenType::BinLit, TokenType::Slash,
+                               TokenType::DecLit, TokenType::EndOfInput]);
+    }
+
+    //this test checks lexing register alias definitions
+    #[test]
+    fn test_register_aliases() {
+        let mut lex = AsmLexer::new("VELOCITY REG VF\nMOV INDEX, I");
+        let types: Vec<TokenType> = lex.tokens()
+                                        .map(|t| t.unwrap().get_type())
+                                        .collect();
+        assert_eq!(types, vec![TokenType::RegDef,
+                               TokenType::Register,
+                               TokenType::Instruction,
+                               TokenType::Instruction,
+                               TokenType::Comma,
+                               TokenType::Register,
+                               TokenType::EndOfInput]);
+        lex = AsmLexer::new("velocity reg vf");
+        let tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_value(), Variant::Text(String::from("VELOCITY")));
+        let tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_value(), Variant::Text(String::from("VF")));
+    }
+
+    //this test checks binary literals of different lengths
+    #[test]
+    fn test_bin_lit_lengths() {
+        let mut lex = AsmLexer::new("%101\n%11111111 %1111111100000000\n%");
+        let mut tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_value(), Variant::Byte(0x05));
+        tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_value(), Variant::Byte(0xFF));
+        tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::BinLit);
+        assert_eq!(tok.get_value(), Variant::Word(0xFF00));
+        let err = lex.get_next_token().err().unwrap();
+        assert!(err.get_type() == LexerErrorType::BadLiteral);
+        assert_eq!(format!("{}", err), "(3:1): malformed literal %");
+    }
+
+    //this test checks lexing labels with digits and underscores
+    #[test]
+    fn test_label_chars() {
+        let mut lex = AsmLexer::new("_loop2: _end_of_data: JMP _loop2");
+        let mut tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::LblDef);
+        assert_eq!(tok.get_value(), Variant::Text(String::from("_loop2")));
+        tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::LblDef);
+        assert_eq!(tok.get_value(), Variant::Text(
+                                        String::from("_end_of_data")));
+        tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::Instruction);
+        tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::Label);
+        assert_eq!(tok.get_value(), Variant::Text(String::from("_loop2")));
+    }
+
+    //this test checks lexing an empty source
+    #[test]
+    fn test_empty_source() {
+        let mut lex = AsmLexer::new("");
+        let tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::EndOfInput);
+    }
+
+    //this test checks that C-style prefixes lex like the sigils
+    #[test]
+    fn test_c_style_prefixes() {
+        let mut lex1 = AsmLexer::new("0xFF 0X1a2 0b10101010 0B00001111");
+        let mut lex2 = AsmLexer::new("$FF $1a2 %10101010 %00001111");
+        loop {
+            let tok = lex1.get_next_token().unwrap();
+            assert_eq!(tok, lex2.get_next_token().unwrap());
+            if tok.get_type() == TokenType::EndOfInput {
+                break;
+            }
+        }
+        let mut lex = AsmLexer::new("0xFF");
+        let tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::HexLit);
+        assert_eq!(tok.get_value(), Variant::Word(0xFF));
+        assert_eq!(tok.get_end(), 4);
+
+        //malformed literals are reported as they were written
+        for (code, text) in [("0x", "0x"), ("0Bz", "0B"), ("0d-", "0d-")] {
+            let err = AsmLexer::new(code).get_next_token().err().unwrap();
+            assert!(err.get_type() == LexerErrorType::BadLiteral);
+            assert_eq!(format!("{}", err),
+                       format!("(1:1): malformed literal {}", text));
+        }
+    }
+
+    //this test checks that line endings and tabs end literals
+    #[test]
+    fn test_crlf_literals() {
+        let mut lex = AsmLexer::new("%1010\r\n%11110000\r\n\t$F\t#9\r\n");
+        let vals: Vec<Variant> = lex.tokens()
+                                    .map(|t| t.unwrap().get_value())
+                                    .collect();
+        assert_eq!(vals[..4], [Variant::Byte(0b1010),
+                               Variant::Byte(0b1111_0000),
+                               Variant::Word(0xF), Variant::Word(9)]);
+        assert_eq!(lex.get_line(), 4);
+    }
+
+    //this test checks lexing with custom comment and literal markers
+    #[test]
+    fn test_sigils() {
+        let sig = Sigils::new().with_comment("//").with_dec('!');
+        let mut lex = AsmLexer::with_sigils("ADD V0, 0d12 // note\n\
+                                            !3 //{ MOV V1\n//} $F/!2",
+                                            &sig);
+        let types: Vec<TokenType> = lex.tokens()
+                                       .map(|t| t.unwrap().get_type())
+                                       .collect();
+        assert_eq!(types, vec![TokenType::Instruction,
+                               TokenType::Register, TokenType::Comma,
+                               TokenType::DecLit, TokenType::DecLit,
+                               TokenType::HexLit, TokenType::Slash,
+                               TokenType::DecLit, TokenType::EndOfInput]);
+    }
+
+    //this test checks the source spans of tokens
+    #[test]
+    fn test_token_spans() {
+        let src = "MOV V1, $FF ;é\n_lbl: JMP _lbl";
+        let mut lex = AsmLexer::new(src);
+        let mut spans = Vec::new();
+        loop {
+            let tok = lex.get_next_token().unwrap();
+            spans.push(&src[tok.get_start()..tok.get_end()]);
+            if tok.get_type() == TokenType::EndOfInput {
+                break;
+            }
+        }
+        assert_eq!(spans, vec!["MOV", "V1", ",", "$FF", "_lbl:",
+                               "JMP", "_lbl", ""]);
+    }
+
+    //lexes a generated source of the given number of lines,
+    //returning the number of tokens found
+    fn lex_lines(lines: usize) -> usize {
+        let src = "MOV V1, $FF ;load a value\n".repeat(lines);
+        let mut lex = AsmLexer::new(src.as_str());
+        let mut count = 0;
+        while lex.get_next_token().unwrap().get_type()
+                != TokenType::EndOfInput {
+            count += 1;
+        }
+        return count;
+    }
+
+    //this test checks lexing a large source
+    #[test]
+    fn test_large_source() {
+        assert_eq!(lex_lines(20000), 20000 * 4);
+    }
+
+    //this test checks that lexing time grows linearly with the source
+    //(it depends on the speed of the machine, so it is only run
+    //on request with `cargo test -- --ignored`)
+    #[test]
+    #[ignore]
+    fn test_linear_lexing() {
+        let start = Instant::now();
+        lex_lines(10000);
+        let small = start.elapsed();
+        let start = Instant::now();
+        lex_lines(40000);
+        let large = start.elapsed();
+        assert!(large < small * 8, "{:?} vs {:?}", large, small);
+    }
+
+    //this test checks that lowercase registers are lexed
+    //the same as uppercase ones
+    #[test]
+    fn test_lowercase_registers() {
+        let mut lex = AsmLexer::new("v0 va vF i I vg");
+        for reg in ["V0", "VA", "VF", "I", "I"] {
+            let tok = lex.get_next_token().unwrap();
+            assert_eq!(tok.get_type(), TokenType::Register);
+            assert_eq!(tok.get_value(), Variant::Text(String::from(reg)));
+        }
+
+        //a name that only starts like a register is not one
+        let tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::Instruction);
+    }
+
+    //this test checks lexing string literals
+    #[test]
+    fn test_str_lits() {
+        let mut lex = AsmLexer::new("DB \"a;b, c.\" ; note\nDB \"\"");
+        lex.get_next_token().unwrap();
+        let tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::StrLit);
+        assert_eq!(tok.get_value(), Variant::Text(String::from("a;b, c.")));
+        lex.get_next_token().unwrap();
+        let tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_value(), Variant::Text(String::new()));
+        assert_eq!(lex.get_next_token().unwrap().get_type(),
+                   TokenType::EndOfInput);
+
+        //strings must be closed on their own line
+        let mut lex = AsmLexer::new("DB \"ab;\nCLS");
+        lex.get_next_token().unwrap();
+        let err = lex.get_next_token().err().unwrap();
+        assert!(err.get_type() == LexerErrorType::UnterminatedStr);
+        assert_eq!(format!("{}", err), "(1:4): unterminated string \"ab;");
+    }
 }
 
 //end of file
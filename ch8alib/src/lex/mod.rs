@@ -29,5 +29,7 @@ mod prep_lexer;
 pub use prep_lexer::PrepLexer;
 mod asm_lexer;
 pub use asm_lexer::AsmLexer;
+mod tokens;
+pub use tokens::Tokens;
 
 //end of file
@@ -26,11 +26,17 @@ use super::TokenType;
 use super::super::util::constants;
 use super::super::util::Variant;
 use super::super::error::LexerError;
+use super::super::error::LexerErrorType;
+use super::super::util::parse_lit;
+use super::super::util::parse_dec_lit;
+use super::super::util::fits_word;
+use super::super::util::Position;
+use super::super::util::Sigils;
 
 /// A preprocessor lexer
 pub struct PrepLexer {
     /// The text being lexed
-    text: String,
+    text: Vec<char>,
 
     /// The current character being lexed
     cur_char: char,
@@ -38,6 +44,9 @@ pub struct PrepLexer {
     /// The current position in the `text`
     pos: usize,
 
+    /// The byte offset of the current position in the `text`
+    byte_pos: usize,
+
     /// The current line number being lexed
     line: u32,
 
@@ -45,10 +54,30 @@ pub struct PrepLexer {
     col: u32,
 
     /// The current address being used to create labels
+    /// (it wraps past the end of memory, which the
+    /// assembler reports as an overflow)
     addr: u16,
 
-    /// The current number of processed nibbles
-    nib_count: u32 
+    /// Whether the next value is an operand of an instruction
+    expect_operand: bool,
+
+    /// The directive waiting for its operand (empty if none)
+    directive: String,
+
+    /// Whether a `DB` or `DW` list is waiting for another value
+    list_pending: bool,
+
+    /// Whether a register alias is waiting for its register
+    alias_pending: bool,
+
+    /// The position the most recently returned token started at
+    tok_pos: Position,
+
+    /// The address of the font written by the first `FONT_DATA`
+    font_base: Option<u16>,
+
+    /// The markers that start comments and literals
+    sigils: Sigils
 }
 
 //implementation
@@ -63,14 +92,59 @@ impl PrepLexer {
     ///
     /// A new `PrepLexer` instance with the given text
     pub fn new(new_text: &str) -> Self {
+        return PrepLexer::with_origin(new_text, constants::MEM_START);
+    }
+
+    /// Constructs a new `PrepLexer` instance that
+    /// starts counting addresses at a given origin
+    ///
+    /// # Arguments
+    ///
+    /// * `new_text` - The text to preprocess
+    /// * `origin` - The address the program is loaded at
+    ///
+    /// # Returns
+    ///
+    /// A new `PrepLexer` instance with the given properties
+    pub fn with_origin(new_text: &str, origin: u16) -> Self {
+        return PrepLexer::with_sigils(new_text, origin, &Sigils::new());
+    }
+
+    /// Constructs a new `PrepLexer` instance that starts counting
+    /// addresses at a given origin and uses the given comment
+    /// and literal markers
+    ///
+    /// # Arguments
+    ///
+    /// * `new_text` - The text to preprocess
+    /// * `origin` - The address the program is loaded at
+    /// * `sigils` - The markers that start comments and literals
+    ///
+    /// # Returns
+    ///
+    /// A new `PrepLexer` instance with the given properties
+    pub fn with_sigils(new_text: &str, origin: u16, sigils: &Sigils)
+        -> Self {
+        //collect the characters of the text
+        //(empty text starts out at the end of input)
+        let chars: Vec<char> = new_text.chars().collect();
+
+        //and return the instance
         return PrepLexer {
-            text: String::from(new_text),
-            cur_char: new_text.chars().nth(0).unwrap(),
+            cur_char: *chars.first().unwrap_or(&'\0'),
+            text: chars,
             pos: 0,
+            byte_pos: 0,
             line: 1,
             col: 1,
-            addr: constants::MEM_START,
-            nib_count: 0
+            addr: origin,
+            expect_operand: false,
+            directive: String::new(),
+            list_pending: false,
+            alias_pending: false,
+            tok_pos: Position::new(1, 1),
+            font_base: None,
+            sigils: sigils.clone()
         };
     }
 
@@ -101,6 +175,34 @@ impl PrepLexer {
         return self.col;
     }
 
+    /// Gets the current position being processed
+    ///
+    /// # Returns
+    ///
+    /// The current line and column being processed
+    pub fn get_position(&self) -> Position {
+        return Position::new(self.line, self.col);
+    }
+
+    /// Gets the address of the font written by `FONT_DATA`
+    ///
+    /// # Returns
+    ///
+    /// The address of the first `FONT_DATA` directive found so far,
+    /// or `None` if there has not been one
+    pub fn get_font_base(&self) -> Option<u16> {
+        return self.font_base;
+    }
+
+    /// Gets the position the most recently returned token started at
+    ///
+    /// # Returns
+    ///
+    /// The line and column of the start of the last token
+    pub fn get_token_position(&self) -> Position {
+        return self.tok_pos;
+    }
+
     /// Gets the next `Token` consumed from the input
     ///
     /// # Returns
@@ -119,110 +221,346 @@ impl PrepLexer {
             //process commas
             if self.cur_char == ',' {
                 self.advance();
+                self.expect_operand = true;
+                self.list_pending = true;
                 continue;
             }
 
             //process periods
-            if self.cur_char == '.' {
+            if (self.cur_char == '.') && !self.at_local_label() {
                self.advance();
                continue;
             }
 
+            //process string literals (before comments,
+            //since a string may hold a comment marker)
+            if self.cur_char == constants::STRING_QUOTE_CHAR {
+                let len = self.consume_str_lit()?;
+                self.close_list();
+                self.place_string(len);
+                continue;
+            }
+
             //process comments
-            if self.cur_char == constants::COMMENT_CHAR {
-                self.consume_comment();
+            if self.at_comment() {
+                self.consume_comment()?;
+                continue;
+            }
+
+            //process line continuations, which are skipped
+            //like whitespace so the statement carries on
+            if self.at_continuation() {
+                self.advance();
+                continue;
+            }
+
+            //process statement separators, which end
+            //any operands and directives like a new line
+            if self.cur_char == constants::SEPARATOR_CHAR {
+                self.advance();
+                self.finish_directive();
+                self.expect_operand = false;
+                self.list_pending = false;
                 continue;
             }
 
             //process register references
-            if (self.cur_char.to_ascii_uppercase() == 'V') 
-                || (self.cur_char.to_ascii_uppercase() == 'I') {
-                self.consume_register();
-                self.update_addr();
+            if self.at_register() {
+                let start = self.byte_pos;
+                self.tok_pos = self.get_position();
+                let reg = self.consume_register();
+                self.expect_operand = false;
+
+                //return the register of an alias definition
+                if self.alias_pending {
+                    self.alias_pending = false;
+                    return Ok(Token::new(TokenType::Register,
+                                         Variant::Text(reg))
+                                .with_span(start, self.byte_pos));
+                }
                 continue;
             }
 
             //process instructions
             if self.cur_char.is_ascii_alphabetic() {
-                self.consume_instr();
-                continue;
-            }
+                let start = self.byte_pos;
+                self.tok_pos = self.get_position();
 
-            //process decimal literals
-            if self.cur_char == constants::DEC_LIT_CHAR {
-                self.consume_dec_lit();
-                self.update_addr();
+                //return the name of an alias definition
+                if let Some(name) = self.consume_instr() {
+                    self.alias_pending = true;
+                    return Ok(Token::new(TokenType::RegDef,
+                                         Variant::Text(name))
+                                .with_span(start, self.byte_pos));
+                }
                 continue;
             }
 
-            //process hex literals
-            if self.cur_char == constants::HEX_LIT_CHAR {
-                self.consume_hex_lit();
-                self.update_addr();
+            //process literals and the expressions they start
+            if self.at_literal() || (self.cur_char == '(') {
+                let (num, wide) = self.consume_expr()?;
+                self.close_list();
+                self.place_value(PrepLexer::field_value(num),
+                                 PrepLexer::value_size(num, wide));
                 continue;
             }
 
-            //process binary literals
-            if self.cur_char == constants::BIN_LIT_CHAR {
-                self.consume_bin_lit();
-                self.update_addr();
+            //process the rest of an expression that started
+            //with a label (it is part of an operand)
+            if is_operator(self.cur_char) {
+                self.advance();
+                self.consume_expr()?;
                 continue;
             }
 
             //process labels
-            if self.cur_char == '_' {
+            if (self.cur_char == '_') || self.at_local_label() {
                 //get the label text
+                let start = self.byte_pos;
+                self.tok_pos = self.get_position();
                 let lbl = self.consume_label();
 
                 //determine whether it's a definition
                 let lidx = lbl.len() - 1;
                 let last = lbl.chars().nth(lidx).unwrap();
+
                 if last == ':' { //is a definition
+                    //finish any directive that came before
+                    self.finish_directive();
+
                     //get the string up to the colon
                     let tlbl = &lbl[0..lidx];
 
                     //and return a token
                     return Ok(Token::new(TokenType::LblDef,
-                                Variant::Text(String::from(tlbl))));
+                                Variant::Text(String::from(tlbl)))
+                                .with_span(start, self.byte_pos));
+                } else if is_section(&lbl) { //is a section marker
+                    //finish any directive that came before
+                    self.finish_directive();
+
+                    //and return a token
+                    return Ok(Token::new(TokenType::Section,
+                            Variant::Text(lbl[1..].to_ascii_lowercase()))
+                                .with_span(start, self.byte_pos));
                 } else { //is not a definition
-                    //move past the label
-                    self.nib_count += 3;
-                    self.update_addr();
+                    //move past the label (label addresses
+                    //in directives are stored as words)
+                    self.close_list();
+                    if self.directive.is_empty() {
+                        self.place_literal(0);
+                    } else {
+                        self.place_value(0, 2);
+                    }
 
                     //return the label token
                     return Ok(Token::new(TokenType::Label,
-                                    Variant::Text(lbl)));
+                                    Variant::Text(lbl))
+                                    .with_span(start, self.byte_pos));
                 }
             }
 
             //if control reaches here, then an unknown 
-            //character was found, so we return an error
-            return Err(LexerError::new(self.line, self.col, 
-                                        self.cur_char));
+            //character was found, so we skip it and return an error
+            let err = LexerError::new(self.get_position(), self.cur_char);
+            self.advance();
+            return Err(err);
         }
 
         //if control reaches here, then
         //the end of input was found
+        self.finish_directive();
+        self.tok_pos = self.get_position();
         return Ok(Token::new(TokenType::EndOfInput, 
-                             Variant::Text(String::from(""))));
+                             Variant::Text(String::from("")))
+                             .with_span(self.byte_pos, self.byte_pos));
     }
 
     /// Advances the lexer to the next character
-    ///
-    /// # Panics
-    ///
-    /// This method will panic if the next character
-    /// cannot be retrieved from the text.
     fn advance(&mut self) {
+        //update the byte offset
+        if self.pos < self.text.len() {
+            self.byte_pos += self.text[self.pos].len_utf8();
+        }
+
         self.pos += 1; //advance the position
 
         //and handle end of input
-        if self.pos > (self.text.len() - 1) {
+        if self.pos >= self.text.len() {
             self.cur_char = '\0';
         } else {
             self.col += 1;
-            self.cur_char = self.text.chars().nth(self.pos).unwrap();
+            self.cur_char = self.text[self.pos];
+        }
+    }
+
+    /// Gets the character after the current one without consuming it
+    ///
+    /// # Returns
+    ///
+    /// The next character in the text, or `'\0'` at the end of input
+    fn peek(&self) -> char {
+        return match self.text.get(self.pos + 1) {
+            Some(c) => *c,
+            None => '\0'
+        };
+    }
+
+    /// Gets the character a given distance after the current one
+    /// without consuming it
+    ///
+    /// # Argument
+    ///
+    /// * `dist` - How many characters ahead to look
+    ///
+    /// # Returns
+    ///
+    /// The character at that distance, or `'\0'` at the end of input
+    fn peek_at(&self, dist: usize) -> char {
+        return match self.text.get(self.pos + dist) {
+            Some(c) => *c,
+            None => '\0'
+        };
+    }
+
+    /// Determines whether the current character starts a register
+    /// reference, rather than a symbol that begins with `V` or `I`
+    ///
+    /// # Returns
+    ///
+    /// Whether a register reference starts at the current position
+    fn at_register(&self) -> bool {
+        if self.cur_char.eq_ignore_ascii_case(&'I') {
+            return !is_ident_char(self.peek());
+        } else if self.cur_char.eq_ignore_ascii_case(&'V') {
+            return self.peek().is_ascii_hexdigit() &&
+                    !is_ident_char(self.peek_at(2));
+        } else {
+            return false;
+        }
+    }
+
+    /// Determines whether the next word on the line is
+    /// the keyword that defines a register alias
+    ///
+    /// # Returns
+    ///
+    /// Whether the alias keyword follows the current position
+    fn at_alias_keyword(&self) -> bool {
+        //skip the spaces before the word
+        let mut i = self.pos;
+        while (i < self.text.len()) &&
+            ((self.text[i] == ' ') || (self.text[i] == '\t')) {
+            i += 1;
+        }
+
+        //collect the word
+        let mut word = String::new();
+        while (i < self.text.len()) && is_ident_char(self.text[i]) {
+            word.push(self.text[i].to_ascii_uppercase());
+            i += 1;
+        }
+
+        //and compare it to the keyword
+        return word == constants::ALIAS_KEYWORD;
+    }
+
+    /// Determines whether a local label starts at the current position
+    ///
+    /// # Returns
+    ///
+    /// Whether the current character starts a local label
+    fn at_local_label(&self) -> bool {
+        //local labels start a new word, unlike the
+        //period that follows `SKIP` in `SKIP.EQ`
+        let after_word = (self.pos > 0) &&
+                            is_ident_char(self.text[self.pos - 1]);
+        return (self.cur_char == constants::LOCAL_LABEL_CHAR) &&
+                !after_word &&
+                (self.peek().is_ascii_alphabetic() || (self.peek() == '_'));
+    }
+
+    /// Determines whether the current character starts a
+    /// C-style literal prefix (such as `0x` or `0b`)
+    ///
+    /// # Argument
+    ///
+    /// * `letter` - The letter that follows the zero in the prefix
+    ///
+    /// # Returns
+    ///
+    /// Whether the text at the current position starts with the prefix
+    fn at_prefix(&self, letter: char) -> bool {
+        return (self.cur_char == '0') &&
+                self.peek().eq_ignore_ascii_case(&letter);
+    }
+
+    /// Advances past the sigil or C-style prefix that starts a literal
+    ///
+    /// # Argument
+    ///
+    /// * `letter` - The letter that follows the zero in the prefix
+    ///
+    /// # Returns
+    ///
+    /// The sigil or prefix, as it was written
+    fn lit_prefix(&mut self, letter: char) -> String {
+        let mut ret = String::new();
+        if self.at_prefix(letter) {
+            ret.push(self.cur_char);
+            self.advance();
         }
+        ret.push(self.cur_char);
+        self.advance();
+        return ret;
+    }
+
+    /// Determines whether a comment marker starts at the
+    /// current position
+    ///
+    /// # Returns
+    ///
+    /// Whether the text at the current position starts a comment
+    fn at_comment(&self) -> bool {
+        return self.comment_at(0);
+    }
+
+    /// Determines whether a comment marker starts a given
+    /// number of characters ahead of the current position
+    ///
+    /// # Argument
+    ///
+    /// * `dist` - How many characters ahead to look
+    ///
+    /// # Returns
+    ///
+    /// Whether the text at that distance starts a comment
+    fn comment_at(&self, dist: usize) -> bool {
+        return self.sigils.get_comment().chars().enumerate()
+                    .all(|(i, c)| self.peek_at(dist + i) == c);
+    }
+
+    /// Determines whether the current character is a backslash that
+    /// continues the statement on the next line, which is the case
+    /// when only blanks or a comment follow it on its line
+    ///
+    /// # Returns
+    ///
+    /// Whether a line continuation starts at the current position
+    fn at_continuation(&self) -> bool {
+        if self.cur_char != constants::SEPARATOR_CHAR {
+            return false;
+        }
+
+        //skip the blanks after the backslash
+        let mut dist = 1;
+        while [' ', '\t'].contains(&self.peek_at(dist)) {
+            dist += 1;
+        }
+
+        //and check for the end of the line
+        let next = self.peek_at(dist);
+        return ['\n', '\r', '\0'].contains(&next) || self.comment_at(dist);
     }
 
     /// Skips whitespace in the input
@@ -238,10 +576,13 @@ impl PrepLexer {
                 break;
             }
 
-            //check for newlines
-            if (self.cur_char == '\n') || (self.cur_char == '\r') {
+            //check for newlines (a CRLF pair counts as one line
+            //break, and the column is reset so that the first
+            //character of the next line lands in column 1)
+            if (self.cur_char == '\n') ||
+                ((self.cur_char == '\r') && (self.peek() != '\n')) {
                 self.line += 1;
-                self.col = 1;
+                self.col = 0;
             }
 
             //and advance the lexer
@@ -249,191 +590,514 @@ impl PrepLexer {
         }
     }
 
-    /// Consumes a comment from the input
-    fn consume_comment(&mut self) {
-        self.advance(); //advance past the semicolon
+    /// Consumes a comment in the text
+    ///
+    /// A comment marker followed by `{` starts a block comment,
+    /// which continues until the next comment marker followed by `}`
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the comment was consumed, or `Err(LexerError)`
+    /// if a block comment is never closed
+    fn consume_comment(&mut self) -> Result<(), LexerError> {
+        //save the position of the comment
+        let pos = self.get_position();
 
-        //and consume to the end of the line
+        //advance past the comment marker
+        let len = self.sigils.get_comment().chars().count();
+        for _ in 0..len {
+            self.advance();
+        }
+
+        //handle block comments
+        if self.cur_char == constants::BLOCK_OPEN_CHAR {
+            self.advance();
+            loop {
+                //check for a missing end of the comment
+                if self.cur_char == '\0' {
+                    let text = format!("{}{}", self.sigils.get_comment(),
+                                       constants::BLOCK_OPEN_CHAR);
+                    return Err(LexerError::with_type(
+                                    LexerErrorType::Unterminated,
+                                    pos, &text));
+                }
+
+                //check for the end of the comment
+                if self.at_comment() &&
+                    (self.peek_at(len) == constants::BLOCK_CLOSE_CHAR) {
+                    for _ in 0..=len {
+                        self.advance();
+                    }
+                    return Ok(());
+                }
+
+                //and skip the character, counting line breaks
+                if self.cur_char.is_ascii_whitespace() {
+                    self.skip_whitespace();
+                } else {
+                    self.advance();
+                }
+            }
+        }
+
+        //otherwise consume to the end of the line
         while (self.cur_char != '\n') && (self.cur_char != '\r') &&
               (self.cur_char != '\0') {
             self.advance();
         }
+        return Ok(());
     }
 
-    /// Updates the address field
-    fn update_addr(&mut self) {
-        if self.nib_count >= 2 {
-            let adv = (self.nib_count as f32 / 2.0).floor() as u16;
-            self.nib_count = 0;
-            self.addr += adv;
+    /// Advances the address past a literal value
+    ///
+    /// # Argument
+    ///
+    /// * `size` - The number of bytes the literal takes up as data
+    fn place_literal(&mut self, size: u16) {
+        //operands are assembled into their instruction,
+        //so only standalone literals take up space
+        if !self.expect_operand {
+            self.addr = self.addr.wrapping_add(size);
+        }
+
+        //and note that the operand has been consumed
+        self.expect_operand = false;
+    }
+
+    /// Places a literal value, either as the operand
+    /// of a pending directive or as a literal
+    ///
+    /// # Arguments
+    ///
+    /// * `num` - The value of the literal
+    /// * `size` - The number of bytes the literal takes up
+    fn place_value(&mut self, num: u16, size: u16) {
+        if self.directive == "DS" {
+            //storage is reserved by the count
+            self.addr = self.addr.wrapping_add(num);
+            self.directive.clear();
+        } else if self.directive == "DB" {
+            //each value in the list is one byte
+            self.addr = self.addr.wrapping_add(1);
+            self.expect_operand = false;
+            self.list_pending = false;
+        } else if self.directive == "DW" {
+            //each value in the list is one word
+            self.addr = self.addr.wrapping_add(2);
+            self.expect_operand = false;
+            self.list_pending = false;
+        } else if self.directive == "ALIGN" {
+            //pad up to the given boundary
+            self.align(num);
+            self.directive.clear();
+        } else {
+            self.place_literal(size);
+        }
+    }
+
+    /// Places a string literal, which takes up
+    /// one byte per character in a `DB` list
+    ///
+    /// # Argument
+    ///
+    /// * `len` - The number of characters in the string
+    fn place_string(&mut self, len: u16) {
+        if self.directive == "DB" {
+            self.addr = self.addr.wrapping_add(len);
+            self.list_pending = false;
         }
+        self.expect_operand = false;
     }
 
-    /// Consumes a register refrence
-    fn consume_register(&mut self) {
+    /// Finishes a directive that did not receive an operand
+    fn finish_directive(&mut self) {
+        if self.directive == "ALIGN" {
+            self.align(constants::DEFAULT_ALIGN);
+        }
+        self.directive.clear();
+    }
+
+    /// Ends a `DB` or `DW` list whose last value
+    /// was not followed by a comma
+    fn close_list(&mut self) {
+        let is_list = (self.directive == "DB") ||
+                        (self.directive == "DW");
+        if is_list && !self.list_pending {
+            self.directive.clear();
+        }
+    }
+
+    /// Advances the address to a multiple of a boundary
+    ///
+    /// # Argument
+    ///
+    /// * `bound` - The boundary to align to
+    fn align(&mut self, bound: u16) {
+        if bound != 0 {
+            let pad = (bound - (self.addr % bound)) % bound;
+            self.addr = self.addr.wrapping_add(pad);
+        }
+    }
+
+    /// Consumes a register reference
+    ///
+    /// # Returns
+    ///
+    /// The uppercase name of the register
+    fn consume_register(&mut self) -> String {
         //check to see what register it is
-        if self.cur_char != 'I' {
+        let mut reg = String::new();
+        if !self.cur_char.eq_ignore_ascii_case(&'I') {
+            reg.push(self.cur_char.to_ascii_uppercase());
             self.advance();
         }
 
-        //advance through the register
+        //and advance through the register
+        reg.push(self.cur_char.to_ascii_uppercase());
         self.advance();
-
-        //and update the nibble count
-        self.nib_count += 1;
+        return reg;
     }
 
     /// Consumes an instruction
-    fn consume_instr(&mut self) {
+    ///
+    /// # Returns
+    ///
+    /// The name of the alias if the instruction turned out
+    /// to be a register alias definition, otherwise `None`
+    fn consume_instr(&mut self) -> Option<String> {
         let mut op = String::new();
-        while (self.cur_char.is_ascii_alphabetic() 
-               || self.cur_char == '.') 
-            && !self.cur_char.is_ascii_whitespace() {
+        while is_ident_char(self.cur_char) || (self.cur_char == '.') {
             op.push(self.cur_char.to_ascii_uppercase());
             self.advance();
         }
 
-        //and increment the nibble count
-        let mut should_update = false;
-        match op.as_str() {
-            "CLS" => {
-                self.nib_count += 4;
-                should_update = true;
-            },
-            "RET" => {
-                self.nib_count += 4;
-                should_update = true;
-            },
-            "ADD" => {
-                self.nib_count += 2;
-                should_update = true;
-            },
-            "AND" => {
-                self.nib_count += 2;
-                should_update = true;
-            },
-            "BCD" => self.nib_count += 3,
-            "CALL" => {
-                self.nib_count += 1;
-                should_update = true;
-            },
-            "DRAW" => {
-                self.nib_count += 1;
-                should_update = true;
-            },
-            "GDL" => self.nib_count += 3,
-            "JMP" => {
-                self.nib_count += 1;
-                should_update = true;
-            },
-            "JPC" => {
-                self.nib_count += 1;
-                should_update = true;
-            },
-            "KEY" => self.nib_count += 3,
-            "MOV" => {
-                self.nib_count += 2;
-                should_update = true;
-            },
-            "OR" => {
-                self.nib_count += 2;
-                should_update = true;
-            },
-            "RAND" => {
-                self.nib_count += 2;
-                should_update = true;
-            },
-            "RDP" => self.nib_count += 3,
-            "RLD" => self.nib_count += 3,
-            "SCH" => self.nib_count += 3,
-            "SDL" => self.nib_count += 3,
-            "SHL" => self.nib_count += 3,
-            "SHR" => self.nib_count += 3,
-            "SKIP.EQ" => {
-                self.nib_count += 2;
-                should_update = true;
-            },
-            "SKIP.NE" => {
-                self.nib_count += 2;
-                should_update = true;
-            },
-            "SKIP.KD" => self.nib_count += 3,
-            "SKIP.KU" => self.nib_count += 3,
-            "SND" => self.nib_count += 3,
-            "SUB" => {
-                self.nib_count += 2;
-                should_update = true;
-            },
-            "SUBN" => {
-                self.nib_count += 2;
-                should_update = true;
-            },
-            "XOR" => {
-                self.nib_count += 2;
-                should_update = true;
-            },
-            _ => {}
-        };
+        //finish any directive that came before
+        self.finish_directive();
+
+        //register alias definitions take up no space
+        if self.at_alias_keyword() {
+            self.skip_whitespace();
+            while is_ident_char(self.cur_char) {
+                self.advance();
+            }
+            return Some(op);
+        }
 
-        //and update the address
-        if should_update {
-            self.update_addr();
+        //the font is a fixed table, so it needs no operands
+        if op == constants::FONT_DIRECTIVE {
+            self.font_base = self.font_base.or(Some(self.addr));
+            self.addr = self.addr.wrapping_add(
+                            constants::FONT_SPRITES.len() as u16);
+            return None;
         }
+
+        //directives are sized by their operands
+        if constants::DIRECTIVES.contains(&op.as_str()) {
+            self.directive = op;
+            self.list_pending = true;
+            return None;
+        }
+
+        //register aliases used as operands take up no space
+        if self.expect_operand {
+            self.expect_operand = false;
+            return None;
+        }
+
+        //every instruction is assembled into two bytes,
+        //except for a long load, which is followed by its address
+        let len = if op == constants::LONG_LOAD_INSTR { 4 } else { 2 };
+        self.addr = self.addr.wrapping_add(len);
+
+        //and determine whether operands follow it
+        self.expect_operand = !constants::NO_ARG_INSTRS
+                                        .contains(&op.as_str());
+        return None;
     }
 
-    /// Consumes a decimal integer literal
-    fn consume_dec_lit(&mut self) {
-        //advance past the sigil
+    /// Consumes a string literal
+    ///
+    /// # Returns
+    ///
+    /// The number of characters between the quotes, or
+    /// `Err(LexerError)` if the string is not closed on its line
+    fn consume_str_lit(&mut self) -> Result<u16, LexerError> {
+        //save the position of the literal
+        let pos = self.get_position();
+
+        //advance past the opening quote
         self.advance();
 
-        //advance through the digits
+        //loop and collect the characters up to the closing quote
+        let mut buf = String::new();
+        while self.cur_char != constants::STRING_QUOTE_CHAR {
+            //check for a missing closing quote
+            if (self.cur_char == '\0') || (self.cur_char == '\n') ||
+                (self.cur_char == '\r') {
+                let text = format!("{}{}", constants::STRING_QUOTE_CHAR,
+                                   buf);
+                return Err(LexerError::with_type(
+                                LexerErrorType::UnterminatedStr,
+                                pos, &text));
+            }
+
+            //only ASCII characters can be written as bytes
+            if !self.cur_char.is_ascii() {
+                let err = LexerError::new(self.get_position(),
+                                          self.cur_char);
+
+                //skip the rest of the string so that it
+                //is not lexed again as code
+                while (self.cur_char != constants::STRING_QUOTE_CHAR) &&
+                    (self.cur_char != '\0') && (self.cur_char != '\n') &&
+                    (self.cur_char != '\r') {
+                    self.advance();
+                }
+                if self.cur_char == constants::STRING_QUOTE_CHAR {
+                    self.advance();
+                }
+
+                return Err(err);
+            }
+
+            buf.push(self.cur_char);
+            self.advance();
+        }
+
+        //and advance past the closing quote
+        self.advance();
+        return Ok(buf.len() as u16);
+    }
+
+    /// Consumes a decimal integer literal
+    ///
+    /// # Returns
+    ///
+    /// The value of the literal, wrapped in a `Result`
+    fn consume_dec_lit(&mut self) -> Result<i32, LexerError> {
+        //save the position of the literal
+        let pos = self.get_position();
+
+        //advance past the sigil (or the C-style prefix),
+        //keeping it for errors
+        let prefix = self.lit_prefix(constants::DEC_LIT_PREFIX);
+
+        //advance through the sign and the digits
         let mut sum = String::new();
+        if self.cur_char == constants::NEG_LIT_CHAR {
+            sum.push(self.cur_char);
+            self.advance();
+        }
         while self.cur_char.is_ascii_digit() {
             sum.push(self.cur_char);
             self.advance();
         }
 
-        //calculate the nibbles in the sum
-        let num = sum.parse::<u16>().unwrap();
-        
-        //and advance the address
-        if num > 0x00FF {
-            self.nib_count += 3;
-        } else if num > 0x000F {
-            self.nib_count += 2;
-        } else {
-            self.nib_count += 1;
-        }
+        //and get the value of the sum
+        return parse_dec_lit(&prefix, &sum, pos);
     }
 
     /// Consumes a hex integer literal
-    fn consume_hex_lit(&mut self) {
-        //advance past the sigil
-        self.advance();
+    ///
+    /// # Returns
+    ///
+    /// The value of the literal, wrapped in a `Result`
+    fn consume_hex_lit(&mut self) -> Result<u16, LexerError> {
+        //save the position of the literal
+        let pos = self.get_position();
+
+        //advance past the sigil (or the C-style prefix),
+        //keeping it for errors
+        let prefix = self.lit_prefix(constants::HEX_LIT_PREFIX);
 
         //advance through the digits
-        let mut nibs_advanced: u32 = 0;
+        let mut digits = String::new();
         while self.cur_char.is_ascii_hexdigit() {
-            nibs_advanced += 1;
+            digits.push(self.cur_char);
             self.advance();
         }
 
-        //and update the nibble count
-        self.nib_count += nibs_advanced;
+        //and get the value of the digits
+        return parse_lit(&prefix, &digits, 16, pos);
     }
 
     /// Consumes a binary literal
-    fn consume_bin_lit(&mut self) {
-        //advance past the sigil
-        self.advance();
+    ///
+    /// # Returns
+    ///
+    /// The value of the literal and whether it is
+    /// long enough to be a word, wrapped in a `Result`
+    fn consume_bin_lit(&mut self) -> Result<(u16, bool), LexerError> {
+        //save the position of the literal
+        let pos = self.get_position();
+
+        //advance past the sigil (or the C-style prefix),
+        //keeping it for errors
+        let prefix = self.lit_prefix(constants::BIN_LIT_PREFIX);
 
         //loop and consume the binary
-        for _i in 0..8 {
+        let mut bits = String::new();
+        while (self.cur_char == '0') || (self.cur_char == '1') {
+            bits.push(self.cur_char);
             self.advance();
         }
 
-        //and advance the address
-        self.nib_count += 2;
+        //get the value of the bits
+        let num = parse_lit(&prefix, &bits, 2, pos)?;
+
+        //and size it by the number of bits
+        return Ok((num, bits.len() > constants::MAX_BYTE_BITS));
+    }
+
+    /// Sizes a value the same way the assembler does
+    ///
+    /// # Arguments
+    ///
+    /// * `num` - The value, or `None` if it cannot be known yet
+    /// * `wide` - Whether the value must be stored as a word
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes the value takes up as data
+    fn value_size(num: Option<i32>, wide: bool) -> u16 {
+        return match num {
+            Some(n) if !wide && (n >= constants::MIN_BYTE_LIT) &&
+                (n <= i32::from(constants::MAX_BYTE_LIT)) => 1,
+            _ => 2
+        };
+    }
+
+    /// Gets the value of a count the same way the assembler does,
+    /// with negatives cut down to their two's-complement byte
+    ///
+    /// # Argument
+    ///
+    /// * `num` - The value, or `None` if it cannot be known yet
+    ///
+    /// # Returns
+    ///
+    /// The count the value stands for
+    fn field_value(num: Option<i32>) -> u16 {
+        return match num {
+            Some(n) if n < 0 => (n as u16) & constants::MAX_BYTE_LIT,
+            Some(n) => n as u16,
+            None => 0
+        };
+    }
+
+    /// Determines whether a literal starts at the current position
+    ///
+    /// # Returns
+    ///
+    /// Whether the current character starts a literal
+    fn at_literal(&self) -> bool {
+        return (self.cur_char == self.sigils.get_dec()) ||
+                (self.cur_char == self.sigils.get_hex()) ||
+                (self.cur_char == self.sigils.get_bin()) ||
+                self.at_prefix(constants::DEC_LIT_PREFIX) ||
+                self.at_prefix(constants::HEX_LIT_PREFIX) ||
+                self.at_prefix(constants::BIN_LIT_PREFIX);
+    }
+
+    /// Skips spaces and tabs, stopping at the end of the line
+    fn skip_blanks(&mut self) {
+        while (self.cur_char == ' ') || (self.cur_char == '\t') {
+            self.advance();
+        }
+    }
+
+    /// Consumes an arithmetic expression
+    ///
+    /// # Returns
+    ///
+    /// The value of the expression (or `None` if it refers to labels
+    /// or cannot be evaluated) and whether it must be stored as a
+    /// word, wrapped in a `Result`
+    fn consume_expr(&mut self) -> Result<(Option<i32>, bool), LexerError> {
+        //get the first term
+        let mut lhs = self.consume_term()?;
+
+        //and add or subtract the following terms
+        loop {
+            self.skip_blanks();
+            let op = self.cur_char;
+            if ((op != '+') && (op != '-')) || self.at_comment() {
+                break;
+            }
+            self.advance();
+            let rhs = self.consume_term()?;
+            lhs = apply_op(op, lhs, rhs);
+        }
+        return Ok(lhs);
+    }
+
+    /// Consumes a term of an arithmetic expression
+    ///
+    /// # Returns
+    ///
+    /// The value of the term and whether it must be
+    /// stored as a word, wrapped in a `Result`
+    fn consume_term(&mut self) -> Result<(Option<i32>, bool), LexerError> {
+        //get the first factor
+        let mut lhs = self.consume_factor()?;
+
+        //and multiply or divide by the following factors
+        loop {
+            self.skip_blanks();
+            let op = self.cur_char;
+            if ((op != '*') && (op != '/')) || self.at_comment() {
+                break;
+            }
+            self.advance();
+            let rhs = self.consume_factor()?;
+            lhs = apply_op(op, lhs, rhs);
+        }
+        return Ok(lhs);
+    }
+
+    /// Consumes a factor of an arithmetic expression
+    ///
+    /// # Returns
+    ///
+    /// The value of the factor and whether it must be
+    /// stored as a word, wrapped in a `Result`
+    fn consume_factor(&mut self)
+        -> Result<(Option<i32>, bool), LexerError> {
+        self.skip_blanks();
+
+        //handle parenthesized expressions
+        if self.cur_char == '(' {
+            self.advance();
+            let ret = self.consume_expr()?;
+            self.skip_blanks();
+            if self.cur_char == ')' {
+                self.advance();
+            }
+            return Ok(ret);
+        }
+
+        //handle literals
+        if (self.cur_char == self.sigils.get_dec()) ||
+            self.at_prefix(constants::DEC_LIT_PREFIX) {
+            return Ok((Some(self.consume_dec_lit()?), false));
+        }
+        if (self.cur_char == self.sigils.get_hex()) ||
+            self.at_prefix(constants::HEX_LIT_PREFIX) {
+            let num = self.consume_hex_lit()?;
+            return Ok((Some(i32::from(num)), false));
+        }
+        if (self.cur_char == self.sigils.get_bin()) ||
+            self.at_prefix(constants::BIN_LIT_PREFIX) {
+            let (num, wide) = self.consume_bin_lit()?;
+            return Ok((Some(i32::from(num)), wide));
+        }
+
+        //label addresses are not known yet
+        //(and a malformed expression is reported by the assembler)
+        if (self.cur_char == '_') || self.at_local_label() {
+            self.consume_label();
+        }
+        return Ok((None, true));
     }
 
     /// Consumes a label
@@ -442,16 +1106,17 @@ impl PrepLexer {
     ///
     /// The consumed label 
     fn consume_label(&mut self) -> String {
-        //create the return value
-        let mut ret = String::from("_");
+        //create the return value, starting with the
+        //underscore (or the period of a local label)
+        let mut ret = String::from(self.cur_char);
 
         //advance past the underscore
         self.advance();
 
         //loop and generate the label
-        while self.cur_char.is_ascii_alphabetic() &&
-            !self.cur_char.is_ascii_whitespace() {
-            ret.push(self.cur_char.to_ascii_uppercase());
+        while self.cur_char.is_ascii_alphanumeric() ||
+            (self.cur_char == '_') {
+            ret.push(self.cur_char);
             self.advance();
         }
 
@@ -467,6 +1132,73 @@ impl PrepLexer {
     } 
 }
 
+/// Determines whether a character can appear in a symbol
+///
+/// # Argument
+///
+/// * `c` - The character to check
+///
+/// # Returns
+///
+/// Whether the character can appear in a symbol
+fn is_ident_char(c: char) -> bool {
+    return c.is_ascii_alphanumeric() || (c == '_');
+}
+
+/// Determines whether a label is a section marker
+///
+/// # Argument
+///
+/// * `lbl` - The label to check, including its period
+///
+/// # Returns
+///
+/// Whether the label names a section, such as `.data`
+fn is_section(lbl: &str) -> bool {
+    return lbl.starts_with(constants::LOCAL_LABEL_CHAR) &&
+            constants::SECTIONS.iter()
+                .any(|name| lbl[1..].eq_ignore_ascii_case(name));
+}
+
+/// Determines whether a character is an arithmetic operator
+///
+/// # Argument
+///
+/// * `c` - The character to check
+///
+/// # Returns
+///
+/// Whether the character is an arithmetic operator
+fn is_operator(c: char) -> bool {
+    return (c == '+') || (c == '-') || (c == '*') || (c == '/');
+}
+
+/// Applies an arithmetic operator to two values
+///
+/// # Arguments
+///
+/// * `op` - The operator to apply
+/// * `lhs` - The left-hand value and whether it is a word
+/// * `rhs` - The right-hand value and whether it is a word
+///
+/// # Returns
+///
+/// The result (or `None` if it cannot be known or is out of range)
+/// and whether it must be stored as a word
+fn apply_op(op: char, lhs: (Option<i32>, bool), rhs: (Option<i32>, bool))
+    -> (Option<i32>, bool) {
+    let val = match (lhs.0, rhs.0) {
+        (Some(a), Some(b)) => match op {
+            '+' => a.checked_add(b),
+            '-' => a.checked_sub(b),
+            '*' => a.checked_mul(b),
+            _ => a.checked_div(b)
+        }.filter(|v| fits_word(*v, a, b)),
+        _ => None
+    };
+    return (val, lhs.1 || rhs.1);
+}
+
 //unit tests
 #[cfg(test)]
 mod tests {
@@ -496,6 +1228,116 @@ mod tests {
         let _tok = lex.get_next_token().unwrap();
     }
 
+    //this test checks error positions across multiple lines
+    #[test]
+    fn test_err_position() {
+        let mut lex = PrepLexer::new("CLS\r\nMOV V1, V2\n  MOV V1, @");
+        let err = lex.get_next_token().err().unwrap();
+        assert_eq!(format!("{}", err), "(3:11): unknown character @");
+    }
+
+    //this test checks that malformed literals are errors
+    #[test]
+    fn test_bad_literals() {
+        let mut lex = PrepLexer::new("MOV V1, #");
+        let mut err = lex.get_next_token().err().unwrap();
+        assert!(err.get_type() == LexerErrorType::BadLiteral);
+        lex = PrepLexer::new("%");
+        err = lex.get_next_token().err().unwrap();
+        assert!(err.get_type() == LexerErrorType::BadLiteral);
+        lex = PrepLexer::new("MOV I, #70000");
+        err = lex.get_next_token().err().unwrap();
+        assert!(err.get_type() == LexerErrorType::Overflow);
+        lex = PrepLexer::new("DB 0X");
+        err = lex.get_next_token().err().unwrap();
+        assert_eq!(format!("{}", err), "(1:4): malformed literal 0X");
+    }
+
+    //this test checks skipping block comments
+    #[test]
+    fn test_block_comments() {
+        let mut lex = PrepLexer::new("CLS ;{\n_a: CLS\n;} _b: ;{");
+        let tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_value(), Variant::Text(String::from("_b")));
+        assert_eq!(lex.get_address(), constants::MEM_START + 2);
+        let err = lex.get_next_token().err().unwrap();
+        assert!(err.get_type() == LexerErrorType::Unterminated);
+        assert_eq!(err.get_line(), 3);
+    }
+
+    //this test checks lexing labels with digits and underscores
+    #[test]
+    fn test_label_chars() {
+        let mut lex = PrepLexer::new("_loop2: CLS _end_of_data: $FF");
+        let mut tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::LblDef);
+        assert_eq!(tok.get_value(), Variant::Text(String::from("_loop2")));
+        assert_eq!(lex.get_address(), constants::MEM_START);
+        tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::LblDef);
+        assert_eq!(tok.get_value(), Variant::Text(
+                                        String::from("_end_of_data")));
+        assert_eq!(lex.get_address(), constants::MEM_START + 2);
+    }
+
+    //this test checks that binary literals are sized by length
+    #[test]
+    fn test_bin_lit_sizes() {
+        let mut lex = PrepLexer::new("%101 _a: %0000000011111111 _b:");
+        lex.get_next_token().unwrap();
+        assert_eq!(lex.get_address(), constants::MEM_START + 1);
+        lex.get_next_token().unwrap();
+        assert_eq!(lex.get_address(), constants::MEM_START + 3);
+    }
+
+    //this test checks that C-style prefixes size like the sigils
+    #[test]
+    fn test_c_style_prefixes() {
+        let mut lex = PrepLexer::new("0xFF _a: 0x100 _b: 0b00001111 _c:");
+        let mut tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::LblDef);
+        assert_eq!(lex.get_address(), constants::MEM_START + 1);
+        tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::LblDef);
+        assert_eq!(lex.get_address(), constants::MEM_START + 3);
+        tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::LblDef);
+        assert_eq!(lex.get_address(), constants::MEM_START + 4);
+    }
+
+    //this test checks that a comment marker that looks like
+    //an operator does not continue an expression
+    #[test]
+    fn test_sigils() {
+        let sig = Sigils::new().with_comment("//");
+        let mut lex = PrepLexer::with_sigils("0d2 // #1\n_a: 0d300//x\n_b:",
+                                             constants::MEM_START, &sig);
+        let mut tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::LblDef);
+        assert_eq!(lex.get_address(), constants::MEM_START + 1);
+        tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::LblDef);
+        assert_eq!(lex.get_address(), constants::MEM_START + 3);
+    }
+
+    //this test checks lexing an empty source
+    #[test]
+    fn test_empty_source() {
+        let mut lex = PrepLexer::new("");
+        let tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::EndOfInput);
+        assert_eq!(lex.get_address(), constants::MEM_START);
+    }
+
+    //this test checks lexing a source that is only comments
+    #[test]
+    fn test_comment_only_source() {
+        let mut lex = PrepLexer::new(";a\r\n\t;{ CLS\n;}\n; b");
+        let tok = lex.get_next_token().unwrap();
+        assert_eq!(tok.get_type(), TokenType::EndOfInput);
+        assert_eq!(lex.get_address(), constants::MEM_START);
+    }
+
     //this test checks token and address generation
     #[test]
     fn test_token_gen() {
@@ -510,7 +1352,7 @@ mod tests {
         };
         assert_eq!(tok.get_type(), TokenType::LblDef);
         assert_eq!(lex.get_address(), constants::MEM_START + 6);
-        assert_eq!(lbl.as_str(), "_BIN");
+        assert_eq!(lbl.as_str(), "_bin");
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::LblDef);
         assert_eq!(lex.get_address(), constants::MEM_START + 7);
@@ -524,7 +1366,7 @@ mod tests {
         };
         assert_eq!(tok.get_type(), TokenType::Label);
         assert_eq!(lex.get_address(), constants::MEM_START + 12);
-        assert_eq!(l2, "_START");
+        assert_eq!(l2, "_start");
         tok = lex.get_next_token().unwrap();
         assert_eq!(tok.get_type(), TokenType::EndOfInput);
         assert_eq!(lex.get_address(), constants::MEM_START + 14);
@@ -25,15 +25,23 @@ use super::TokenType;
 use super::super::util::Variant;
 use std::cmp;
 use std::clone;
+use serde::Serialize;
 
 /// A program token
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Token {
     /// The type of the `Token`
+    #[serde(rename = "type")]
     ttype: TokenType,
 
     /// The value of the `Token`
-    value: Variant 
+    value: Variant,
+
+    /// The byte offset in the source that the `Token` starts at
+    start: usize,
+
+    /// The byte offset in the source just past the end of the `Token`
+    end: usize
 }
 
 //implementation
@@ -51,10 +59,28 @@ impl Token {
     pub fn new(new_type: TokenType, new_value: Variant) -> Self {
         return Token {
             ttype: new_type,
-            value: new_value 
+            value: new_value,
+            start: 0,
+            end: 0
         };
     }
 
+    /// Attaches a source span to the `Token`
+    ///
+    /// # Arguments
+    ///
+    /// * `new_start` - The byte offset the token starts at
+    /// * `new_end` - The byte offset just past the end of the token
+    ///
+    /// # Returns
+    ///
+    /// The `Token` with the given span
+    pub fn with_span(mut self, new_start: usize, new_end: usize) -> Self {
+        self.start = new_start;
+        self.end = new_end;
+        return self;
+    }
+
     /// Gets the type of the `Token`
     ///
     /// # Returns
@@ -72,9 +98,47 @@ impl Token {
     pub fn get_value(&self) -> Variant {
         return self.value.clone();
     }
+
+    /// Gets a reference to the type of the `Token`
+    ///
+    /// # Returns
+    ///
+    /// A reference to the type of the `Token`
+    pub fn get_type_ref(&self) -> &TokenType {
+        return &self.ttype;
+    }
+
+    /// Gets a reference to the value of the `Token`
+    ///
+    /// # Returns
+    ///
+    /// A reference to the value of the `Token`
+    pub fn get_value_ref(&self) -> &Variant {
+        return &self.value;
+    }
+
+    /// Gets the byte offset the `Token` starts at in the source
+    ///
+    /// # Returns
+    ///
+    /// The byte offset of the first character of the `Token`
+    pub fn get_start(&self) -> usize {
+        return self.start;
+    }
+
+    /// Gets the byte offset just past the end of the `Token`
+    ///
+    /// # Returns
+    ///
+    /// The byte offset after the last character of the `Token`
+    pub fn get_end(&self) -> usize {
+        return self.end;
+    }
 }
 
 //PartialEq implementation
+//(tokens with the same type and value are equal
+//no matter where they appear in the source)
 impl cmp::PartialEq for Token {
     fn eq(&self, rhs: &Self) -> bool {
         return (self.ttype == rhs.ttype) && (self.value == rhs.value);
@@ -86,7 +150,9 @@ impl clone::Clone for Token {
     fn clone(&self) -> Self {
         return Token {
             ttype: self.ttype.clone(),
-            value: self.value.clone()
+            value: self.value.clone(),
+            start: self.start,
+            end: self.end
         };
     }
 }
@@ -113,6 +179,36 @@ mod tests {
         let t2 = Token::new(TokenType::Register, Variant::Byte(0x5));
         assert_ne!(t1, t2);
     }
+
+    //this test checks borrowing the type and value
+    #[test]
+    fn test_refs() {
+        let t = Token::new(TokenType::Label, Variant::Text(String::from("_A")));
+        assert_eq!(*t.get_type_ref(), TokenType::Label);
+        assert_eq!(*t.get_value_ref(), Variant::Text(String::from("_A")));
+    }
+
+    //this test checks serializing a token to JSON
+    #[test]
+    fn test_serialize() {
+        let t = Token::new(TokenType::HexLit, Variant::Word(0x200))
+                    .with_span(4, 8);
+        assert_eq!(serde_json::to_string(&t).unwrap(),
+                   "{\"type\":\"HexLit\",\"value\":{\"Word\":512},\
+                    \"start\":4,\"end\":8}");
+    }
+
+    //this test checks attaching a span
+    #[test]
+    fn test_span() {
+        let t = Token::new(TokenType::Comma, Variant::Text(String::from(",")))
+                    .with_span(4, 5);
+        assert_eq!(t.get_start(), 4);
+        assert_eq!(t.get_end(), 5);
+        assert_eq!(t.clone().get_start(), 4);
+        assert_eq!(t, Token::new(TokenType::Comma,
+                                 Variant::Text(String::from(","))));
+    }
 }
 
 //end of file
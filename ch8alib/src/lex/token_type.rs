@@ -22,9 +22,10 @@
 
 //imports
 use std::fmt;
+use serde::Serialize;
 
 /// The type of a program `Token`
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum TokenType {
     /// An assembly instruction
     Instruction,
@@ -38,24 +39,60 @@ pub enum TokenType {
     /// A register reference
     Register,
 
+    /// A register alias definition
+    RegDef,
+
     /// A decimal integer literal
     DecLit,
 
+    /// A negative decimal integer literal
+    NegLit,
+
     /// A hex integer literal
     HexLit,
 
     /// A binary byte literal
     BinLit,
 
+    /// A string literal
+    StrLit,
+
     /// A skip condition
     SkipCond,
 
+    /// A timer name
+    Timer,
+
     /// A comma character
     Comma,
 
     /// A period character
     Period,
 
+    /// A plus sign
+    Plus,
+
+    /// A minus sign
+    Minus,
+
+    /// An asterisk
+    Asterisk,
+
+    /// A forward slash
+    Slash,
+
+    /// A left parenthesis
+    LeftParen,
+
+    /// A right parenthesis
+    RightParen,
+
+    /// A separator between statements on the same line
+    Separator,
+
+    /// A section marker (`.code` or `.data`)
+    Section,
+
     /// End of source input
     EndOfInput
 }
@@ -74,12 +111,24 @@ impl TokenType {
             TokenType::Label => "label",
             TokenType::LblDef => "label definition",
             TokenType::Register => "register",
+            TokenType::RegDef => "register alias definition",
             TokenType::DecLit => "decimal literal",
+            TokenType::NegLit => "negative literal",
             TokenType::HexLit => "hex literal",
             TokenType::BinLit => "binary literal",
+            TokenType::StrLit => "string literal",
             TokenType::SkipCond => "skip condition",
+            TokenType::Timer => "timer",
             TokenType::Comma => "comma",
             TokenType::Period => "period",
+            TokenType::Plus => "plus sign",
+            TokenType::Minus => "minus sign",
+            TokenType::Asterisk => "asterisk",
+            TokenType::Slash => "slash",
+            TokenType::LeftParen => "left parenthesis",
+            TokenType::RightParen => "right parenthesis",
+            TokenType::Separator => "statement separator",
+            TokenType::Section => "section marker",
             TokenType::EndOfInput => "EOF"
         };
 
@@ -103,4 +152,19 @@ impl fmt::Display for TokenType {
     }
 }
 
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the TokenType enum
+    use super::*;
+
+    //this test checks comparing token types
+    #[test]
+    fn test_equality() {
+        assert_eq!(TokenType::Comma, TokenType::Comma);
+        assert_ne!(TokenType::Comma, TokenType::Period);
+        assert_ne!(TokenType::Label, TokenType::LblDef);
+    }
+}
+
 //end of file
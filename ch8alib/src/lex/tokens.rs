@@ -0,0 +1,110 @@
+/*
+ * tokens.rs
+ * Defines an iterator over the tokens produced by a lexer
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use super::AsmLexer;
+use super::Token;
+use super::TokenType;
+use super::super::error::LexerError;
+
+/// Iterates over the tokens produced by an `AsmLexer`
+pub struct Tokens<'a> {
+    /// The lexer producing the tokens
+    lexer: &'a mut AsmLexer,
+
+    /// Whether the end of input has been yielded
+    done: bool
+}
+
+//implementation
+impl<'a> Tokens<'a> {
+    /// Constructs a new `Tokens` instance
+    ///
+    /// # Argument
+    ///
+    /// * `new_lexer` - The lexer to draw tokens from
+    ///
+    /// # Returns
+    ///
+    /// A new `Tokens` instance over the given lexer
+    pub fn new(new_lexer: &'a mut AsmLexer) -> Self {
+        return Tokens {
+            lexer: new_lexer,
+            done: false
+        };
+    }
+}
+
+//Iterator implementation
+impl Iterator for Tokens<'_> {
+    type Item = Result<Token, LexerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        //stop once the end of input has been yielded
+        if self.done {
+            return None;
+        }
+
+        //get the next token
+        let res = self.lexer.get_next_token();
+        if let Ok(ref tok) = res {
+            self.done = *tok.get_type_ref() == TokenType::EndOfInput;
+        }
+
+        //and return it
+        return Some(res);
+    }
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //imports
+    use super::*;
+
+    //this test checks collecting the token stream
+    #[test]
+    fn test_collect() {
+        let mut lex = AsmLexer::new("CLS\nJMP _end");
+        let types: Vec<TokenType> = lex.tokens()
+                                        .map(|t| t.unwrap().get_type())
+                                        .collect();
+        assert_eq!(types, vec![TokenType::Instruction,
+                               TokenType::Instruction,
+                               TokenType::Label,
+                               TokenType::EndOfInput]);
+        assert!(lex.tokens().next().is_some());
+    }
+
+    //this test checks that errors are yielded in the stream
+    #[test]
+    fn test_errors() {
+        let mut lex = AsmLexer::new("CLS @ RET");
+        let res: Vec<Result<Token, LexerError>> = lex.tokens().collect();
+        assert_eq!(res.len(), 4);
+        assert!(res[1].is_err());
+        assert_eq!(res[2].as_ref().unwrap().get_type(),
+                   TokenType::Instruction);
+    }
+}
+
+//end of file
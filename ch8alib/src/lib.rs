@@ -20,6 +20,24 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
+//! The library behind the `ch8asm` Chip-8 assembler
+//!
+//! The library has no command-line code, so it can be used on its own,
+//! for example to assemble code from inside an emulator:
+//!
+//! ```
+//! use ch8alib::codegen::Assembler;
+//! use ch8alib::codegen::AsmOptions;
+//!
+//! let code = "_start:\nCLS\nJMP _start";
+//! let mut asm = Assembler::unnamed(code, &AsmOptions::new()).unwrap();
+//! asm.assemble().unwrap();
+//! assert_eq!(asm.get_bytes(), &[0x00, 0xE0, 0x12, 0x00]);
+//! ```
+
+//lint configuration (explicit returns are house style)
+#![allow(clippy::needless_return)]
+
 //exports
 pub mod util;
 pub mod lex;
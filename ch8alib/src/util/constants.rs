@@ -28,6 +28,12 @@ pub const MEM_START: u16 = 0x0200;
 /// The character that denotes the start of a comment
 pub const COMMENT_CHAR: char = ';';
 
+/// The character that follows a semicolon to open a block comment
+pub const BLOCK_OPEN_CHAR: char = '{';
+
+/// The character that follows a semicolon to close a block comment
+pub const BLOCK_CLOSE_CHAR: char = '}';
+
 /// The character that denotes the start of a decimal literal
 pub const DEC_LIT_CHAR: char = '#';
 
@@ -37,7 +43,97 @@ pub const HEX_LIT_CHAR: char = '$';
 /// The character that denotes the start of a binary literal
 pub const BIN_LIT_CHAR: char = '%';
 
+/// The character that follows the decimal sigil in a negative literal
+pub const NEG_LIT_CHAR: char = '-';
+
+/// The magnitude of the most negative decimal literal (a signed word)
+pub const MAX_NEG_LIT: u16 = 32768;
+
+/// The character that starts a local label
+pub const LOCAL_LABEL_CHAR: char = '.';
+
+/// The character that separates statements on the same line
+pub const SEPARATOR_CHAR: char = '\\';
+
+/// The character that starts and ends a string literal
+pub const STRING_QUOTE_CHAR: char = '"';
+
+/// The keyword that defines a register alias
+pub const ALIAS_KEYWORD: &str = "REG";
+
+/// The most digits a binary literal can have and still be a byte
+pub const MAX_BYTE_BITS: usize = 8;
+
+/// The letter that follows a zero to denote a C-style hex literal
+pub const HEX_LIT_PREFIX: char = 'x';
+
+/// The letter that follows a zero to denote a C-style binary literal
+pub const BIN_LIT_PREFIX: char = 'b';
+
+/// The letter that follows a zero to denote a C-style decimal literal
+pub const DEC_LIT_PREFIX: char = 'd';
+
 /// The amount of memory available on a Chip-8 system
 pub const MEM_SIZE: u16 = 0x1000;
 
+/// The largest address that fits in an instruction
+pub const MAX_ADDR: u16 = 0x0FFF;
+
+/// The largest literal value that is assembled as a single byte
+pub const MAX_BYTE_LIT: u16 = 0x00FF;
+
+/// The most negative value that is assembled as a single byte
+pub const MIN_BYTE_LIT: i32 = -128;
+
+/// The mnemonics accepted as `SKIP` conditions
+pub const SKIP_CONDS: [&str; 4] = ["EQ", "NE", "KD", "KU"];
+
+/// The names of the timers that `MOV` can read and write
+pub const TIMERS: [&str; 2] = ["DELAY", "SOUND"];
+
+/// The mnemonics of assembler directives
+pub const DIRECTIVES: [&str; 5] = ["DS", "DB", "DW", "ALIGN", "FONT_DATA"];
+
+/// The directive that writes the standard hex font
+pub const FONT_DIRECTIVE: &str = "FONT_DATA";
+
+/// The label defined at the start of the font written by `FONT_DATA`
+pub const FONT_LABEL: &str = "_font_data";
+
+/// The standard Chip-8 font, a five-byte sprite
+/// for each hex digit from 0 to F
+pub const FONT_SPRITES: [u8; 80] = [
+    0xF0, 0x90, 0x90, 0x90, 0xF0, //0
+    0x20, 0x60, 0x20, 0x20, 0x70, //1
+    0xF0, 0x10, 0xF0, 0x80, 0xF0, //2
+    0xF0, 0x10, 0xF0, 0x10, 0xF0, //3
+    0x90, 0x90, 0xF0, 0x10, 0x10, //4
+    0xF0, 0x80, 0xF0, 0x10, 0xF0, //5
+    0xF0, 0x80, 0xF0, 0x90, 0xF0, //6
+    0xF0, 0x10, 0x20, 0x40, 0x40, //7
+    0xF0, 0x90, 0xF0, 0x90, 0xF0, //8
+    0xF0, 0x90, 0xF0, 0x10, 0xF0, //9
+    0xF0, 0x90, 0xF0, 0x90, 0x90, //A
+    0xE0, 0x90, 0xE0, 0x90, 0xE0, //B
+    0xF0, 0x80, 0x80, 0x80, 0xF0, //C
+    0xE0, 0x90, 0x90, 0x90, 0xE0, //D
+    0xF0, 0x80, 0xF0, 0x80, 0xF0, //E
+    0xF0, 0x80, 0xF0, 0x80, 0x80  //F
+];
+
+/// The names of the section markers, which are written
+/// like local labels (`.code` and `.data`)
+pub const SECTIONS: [&str; 2] = ["code", "data"];
+
+/// The boundary `ALIGN` pads to when no boundary is given
+pub const DEFAULT_ALIGN: u16 = 2;
+
+/// The mnemonic of the XO-Chip instruction that loads a 16-bit
+/// address into `I`, which takes up four bytes
+pub const LONG_LOAD_INSTR: &str = "MOVL";
+
+/// The mnemonics of instructions that take no operands
+pub const NO_ARG_INSTRS: [&str; 7] = ["CLS", "RET", "HIGH", "LOW",
+                                       "SCR", "SCL", "EXIT"];
+
 //end of file
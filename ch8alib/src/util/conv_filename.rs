@@ -21,31 +21,24 @@
  */
 
 //usage statements
-use std::ffi::OsString;
 use std::path::Path;
 
 /// Converts a filename extension from source (`.c8a`) to binary (`.c8`) 
 ///
 /// # Argument
 ///
-/// * `fname` - The filename to convert
+/// * `fname` - The filename to convert, which may include a directory
 /// 
 /// # Returns
 ///
-/// The argument filename with the extension `.c8`
+/// The argument filename in the same directory with the extension
+/// `.c8`, which replaces any other extension or is added if there is none
 pub fn conv_filename(fname: &str) -> String {
-    //create the path
-    let path = Path::new(fname);
-
-    //get the filename without the extension
-    let tmp = OsString::from(path.file_stem().unwrap());
-    let mut ret = tmp.into_string().unwrap();
-
-    //append the new extension
-    ret.push_str(".c8");
+    //replace the extension, keeping the rest of the path
+    let path = Path::new(fname).with_extension("c8");
 
     //and return the final filename
-    return ret;
+    return path.to_string_lossy().into_owned();
 }
 
 //unit tests
@@ -61,6 +54,33 @@ mod tests {
         let fnc = conv_filename(fname);
         assert_eq!(fnc.as_str(), "hello.c8");
     }
+
+    //this test checks that directories are kept
+    #[test]
+    fn test_directories() {
+        assert_eq!(conv_filename("roms/games/pong.c8a"),
+                   "roms/games/pong.c8");
+        assert_eq!(conv_filename("../pong.c8a"), "../pong.c8");
+        assert_eq!(conv_filename("/tmp/my.roms/pong.c8a"),
+                   "/tmp/my.roms/pong.c8");
+    }
+
+    //this test checks paths written with backslashes
+    #[test]
+    fn test_backslash_paths() {
+        assert_eq!(conv_filename("C:\\roms\\game.c8a"),
+                   "C:\\roms\\game.c8");
+        assert_eq!(conv_filename("roms\\game.c8a"), "roms\\game.c8");
+    }
+
+    //this test checks filenames with other extensions or none
+    #[test]
+    fn test_other_extensions() {
+        assert_eq!(conv_filename("game"), "game.c8");
+        assert_eq!(conv_filename("roms/game"), "roms/game.c8");
+        assert_eq!(conv_filename("game.asm"), "game.c8");
+        assert_eq!(conv_filename("game.tar.c8a"), "game.tar.c8");
+    }
 }
 
 //end of file
@@ -0,0 +1,65 @@
+/*
+ * decode_source.rs
+ * Defines a function that decodes the bytes of a source file
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use std::str;
+
+/// Decodes the bytes of a source file into text for the lexers
+///
+/// Valid UTF-8 is decoded as is. Otherwise, each byte becomes the
+/// character with the same value, so stray high bytes survive in
+/// comments, while the lexers still reject them anywhere else,
+/// since code is plain ASCII. That includes strings, as a decoded
+/// character no longer says which bytes it was read from.
+///
+/// # Argument
+///
+/// * `bytes` - The bytes of the source file
+///
+/// # Returns
+///
+/// The text of the source file
+pub fn decode_source(bytes: &[u8]) -> String {
+    return match str::from_utf8(bytes) {
+        Ok(text) => String::from(text),
+        Err(_) => bytes.iter().map(|&b| char::from(b)).collect()
+    };
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the decode_source function
+    use super::*;
+
+    //this test checks decoding UTF-8 and non-UTF-8 sources
+    #[test]
+    fn test_decode_source() {
+        assert_eq!(decode_source("CLS ; caf\u{E9}".as_bytes()),
+                   "CLS ; caf\u{E9}");
+        assert_eq!(decode_source(b"CLS ; caf\xE9\xFF"),
+                   "CLS ; caf\u{E9}\u{FF}");
+        assert_eq!(decode_source(b""), "");
+    }
+}
+
+//end of file
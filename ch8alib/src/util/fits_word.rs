@@ -0,0 +1,67 @@
+/*
+ * fits_word.rs
+ * Defines a function that checks the range of arithmetic results
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statement
+use super::constants;
+
+/// Determines whether the result of an arithmetic operation can be
+/// used as a value. Results must fit in a word, and can only fall
+/// below zero if one of the operands was already negative.
+///
+/// # Arguments
+///
+/// * `val` - The result of the operation
+/// * `lhs` - The left-hand operand
+/// * `rhs` - The right-hand operand
+///
+/// # Returns
+///
+/// Whether the result is in range
+pub fn fits_word(val: i32, lhs: i32, rhs: i32) -> bool {
+    //check the bounds of a word
+    if (val > i32::from(u16::MAX)) ||
+        (val < -i32::from(constants::MAX_NEG_LIT)) {
+        return false;
+    }
+
+    //and ensure that unsigned values stay unsigned
+    return (val >= 0) || (lhs < 0) || (rhs < 0);
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the fits_word function
+    use super::*;
+
+    //this test checks the range of arithmetic results
+    #[test]
+    fn test_fits_word() {
+        assert!(fits_word(0xFFFF, 0xFF00, 0xFF));
+        assert!(!fits_word(0x10000, 0xFFFF, 1));
+        assert!(!fits_word(-1, 1, 2));
+        assert!(fits_word(-2, -1, 2));
+        assert!(!fits_word(-32769, -32768, 1));
+    }
+}
+
+//end of file
@@ -0,0 +1,68 @@
+/*
+ * mark_source.rs
+ * Defines a function that marks a column in a line of source code
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+/// Marks a column in a line of source code with a caret
+///
+/// # Arguments
+///
+/// * `src` - The line of source code
+/// * `col` - The column to mark (starting at 1)
+///
+/// # Returns
+///
+/// The source line followed by a caret line, each on its own
+/// indented line and preceded by a newline
+pub fn mark_source(src: &str, col: u32) -> String {
+    //create the return value
+    let mut ret = String::from("\n    ");
+    ret.push_str(src);
+    ret.push_str("\n    ");
+
+    //pad up to the column, keeping tabs so the caret lines up
+    for c in src.chars().take((col as usize).saturating_sub(1)) {
+        if c == '\t' {
+            ret.push('\t');
+        } else {
+            ret.push(' ');
+        }
+    }
+
+    //and add the caret
+    ret.push('^');
+    return ret;
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the mark_source function
+    use super::*;
+
+    //this test checks marking a column
+    #[test]
+    fn test_mark_source() {
+        assert_eq!(mark_source("MOV V1, @", 9), "\n    MOV V1, @\n            ^");
+        assert_eq!(mark_source("\tCLS", 2), "\n    \tCLS\n    \t^");
+    }
+}
+
+//end of file
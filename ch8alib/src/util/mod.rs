@@ -26,5 +26,23 @@ mod variant;
 pub use variant::Variant;
 mod conv_filename;
 pub use conv_filename::conv_filename;
+mod parse_lit;
+pub use parse_lit::parse_lit;
+mod parse_dec_lit;
+pub use parse_dec_lit::parse_dec_lit;
+mod fits_word;
+pub use fits_word::fits_word;
+mod mark_source;
+pub use mark_source::mark_source;
+mod position;
+pub use position::Position;
+mod sigils;
+pub use sigils::Sigils;
+mod reg_name;
+pub use reg_name::reg_name;
+mod skip_name;
+pub use skip_name::skip_name;
+mod decode_source;
+pub use decode_source::decode_source;
 
 //end of file
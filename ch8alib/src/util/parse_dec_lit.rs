@@ -0,0 +1,105 @@
+/*
+ * parse_dec_lit.rs
+ * Defines a function that converts a signed decimal literal
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use super::constants;
+use super::parse_lit;
+use super::Position;
+use super::super::error::LexerError;
+use super::super::error::LexerErrorType;
+
+/// Converts the digits of a decimal literal into an integer.
+/// Negative literals must fit in a signed word, so they can be
+/// at most 32768 below zero.
+///
+/// # Arguments
+///
+/// * `prefix` - The text that introduced the literal,
+///   such as `#` or `0d`
+/// * `digits` - The digits of the literal, with an optional minus sign
+/// * `pos` - The position the literal starts at
+///
+/// # Returns
+///
+/// The value of the literal, or a `LexerError` describing why
+/// the literal is malformed or out of range
+pub fn parse_dec_lit(prefix: &str, digits: &str, pos: Position)
+    -> Result<i32, LexerError> {
+    //positive literals are converted as they are
+    let mag = match digits.strip_prefix(constants::NEG_LIT_CHAR) {
+        Some(m) => m,
+        None => return Ok(i32::from(parse_lit(prefix, digits, 10, pos)?))
+    };
+
+    //rebuild the literal text for errors
+    let text = format!("{}{}", prefix, digits);
+
+    //get the magnitude of the literal
+    let num = match parse_lit(prefix, mag, 10, pos) {
+        Ok(n) => n,
+        Err(le) => return Err(LexerError::with_type(le.get_type(), pos,
+                                                    &text))
+    };
+
+    //ensure that it fits in a signed word
+    if num > constants::MAX_NEG_LIT {
+        return Err(LexerError::with_type(LexerErrorType::Overflow,
+                                         pos, &text));
+    }
+
+    //and return its negation
+    return Ok(-i32::from(num));
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the parse_dec_lit function
+    use super::*;
+
+    //this test checks converting signed literals
+    #[test]
+    fn test_signed_literals() {
+        let pos = Position::new(1, 1);
+        assert_eq!(parse_dec_lit("#", "300", pos).unwrap(), 300);
+        assert_eq!(parse_dec_lit("#", "-1", pos).unwrap(), -1);
+        assert_eq!(parse_dec_lit("#", "-128", pos).unwrap(), -128);
+        assert_eq!(parse_dec_lit("#", "-32768", pos).unwrap(), -32768);
+        assert_eq!(parse_dec_lit("0d", "-0", pos).unwrap(), 0);
+    }
+
+    //this test checks that bad negative literals are an error
+    #[test]
+    fn test_bad_negatives() {
+        let pos = Position::new(2, 3);
+        let err = parse_dec_lit("#", "-32769", pos).err().unwrap();
+        assert!(err.get_type() == LexerErrorType::Overflow);
+        assert_eq!(format!("{}", err),
+                   "(2:3): literal out of range #-32769");
+        let err = parse_dec_lit("#", "-", pos).err().unwrap();
+        assert_eq!(format!("{}", err), "(2:3): malformed literal #-");
+        let err = parse_dec_lit("0d", "-", pos).err().unwrap();
+        assert_eq!(format!("{}", err), "(2:3): malformed literal 0d-");
+    }
+}
+
+//end of file
@@ -0,0 +1,98 @@
+/*
+ * parse_lit.rs
+ * Defines a function that converts the digits of a literal
+ * into an integer
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use std::num::IntErrorKind;
+use super::super::error::LexerError;
+use super::super::error::LexerErrorType;
+use super::Position;
+
+/// Converts the digits of a numeric literal into an integer
+///
+/// # Arguments
+///
+/// * `prefix` - The text that introduced the literal,
+///   such as `$` or `0x`
+/// * `digits` - The digits of the literal
+/// * `radix` - The base of the digits
+/// * `pos` - The position the literal starts at
+///
+/// # Returns
+///
+/// The value of the literal, or a `LexerError` describing why
+/// the literal is malformed or too large
+pub fn parse_lit(prefix: &str, digits: &str, radix: u32,
+                 pos: Position) -> Result<u16, LexerError> {
+    return match u16::from_str_radix(digits, radix) {
+        Ok(n) => Ok(n),
+        Err(e) => {
+            //rebuild the literal text for the error
+            let text = format!("{}{}", prefix, digits);
+
+            //determine what went wrong
+            let etype = match *e.kind() {
+                IntErrorKind::PosOverflow => LexerErrorType::Overflow,
+                _ => LexerErrorType::BadLiteral
+            };
+
+            //and return the error
+            Err(LexerError::with_type(etype, pos, &text))
+        }
+    };
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the parse_lit function
+    use super::*;
+
+    //this test checks parsing valid literals
+    #[test]
+    fn test_valid_literals() {
+        let pos = Position::new(1, 1);
+        assert_eq!(parse_lit("#", "212", 10, pos).unwrap(), 212);
+        assert_eq!(parse_lit("$", "FC00", 16, pos).unwrap(), 0xFC00);
+        assert_eq!(parse_lit("%", "00001111", 2, pos).unwrap(), 0x0F);
+    }
+
+    //this test checks that missing digits are an error
+    #[test]
+    fn test_missing_digits() {
+        let err = parse_lit("#", "", 10, Position::new(2, 5)).err().unwrap();
+        assert_eq!(format!("{}", err), "(2:5): malformed literal #");
+        let err = parse_lit("0x", "", 16, Position::new(2, 5)).err().unwrap();
+        assert_eq!(format!("{}", err), "(2:5): malformed literal 0x");
+    }
+
+    //this test checks that values too large for a word are an error
+    #[test]
+    fn test_overflow() {
+        let pos = Position::new(3, 9);
+        let err = parse_lit("#", "70000", 10, pos).err().unwrap();
+        assert!(err.get_type() == LexerErrorType::Overflow);
+        assert_eq!(format!("{}", err), "(3:9): literal out of range #70000");
+    }
+}
+
+//end of file
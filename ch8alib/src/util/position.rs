@@ -0,0 +1,98 @@
+/*
+ * position.rs
+ * Defines a struct that represents a position in source code
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use std::fmt;
+
+/// A line and column in source code
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Position {
+    /// The line number
+    line: u32,
+
+    /// The column number
+    col: u32
+}
+
+//implementation
+impl Position {
+    /// Constructs a new `Position` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `new_line` - The line number
+    /// * `new_col` - The column number
+    ///
+    /// # Returns
+    ///
+    /// A new `Position` instance with the given properties
+    pub fn new(new_line: u32, new_col: u32) -> Self {
+        return Position {
+            line: new_line,
+            col: new_col
+        };
+    }
+
+    /// Gets the line number of the position
+    ///
+    /// # Returns
+    ///
+    /// The line number of the position
+    pub fn get_line(&self) -> u32 {
+        return self.line;
+    }
+
+    /// Gets the column number of the position
+    ///
+    /// # Returns
+    ///
+    /// The column number of the position
+    pub fn get_column(&self) -> u32 {
+        return self.col;
+    }
+}
+
+//Display implementation
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.col)
+    }
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the Position struct
+    use super::*;
+
+    //this test checks getting and formatting a position
+    #[test]
+    fn test_position() {
+        let pos = Position::new(12, 5);
+        assert_eq!(pos.get_line(), 12);
+        assert_eq!(pos.get_column(), 5);
+        assert_eq!(format!("({})", pos), "(12:5)");
+        assert_eq!(pos, Position::new(12, 5));
+    }
+}
+
+//end of file
@@ -0,0 +1,60 @@
+/*
+ * reg_name.rs
+ * Defines a function that gets the source spelling of a register
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//crate import
+extern crate ch8_isa;
+
+//usage statement
+use ch8_isa::data::Register;
+
+/// Gets the name of a register as it is written in source code
+///
+/// # Argument
+///
+/// * `reg` - The register to name
+///
+/// # Returns
+///
+/// The name of the register, such as `VA` or `I`
+pub fn reg_name(reg: &Register) -> String {
+    return match *reg {
+        Register::I => String::from("I"),
+        _ => format!("V{:X}", reg.to_id())
+    };
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the reg_name function
+    use super::*;
+
+    //this test checks naming registers
+    #[test]
+    fn test_reg_name() {
+        assert_eq!(reg_name(&Register::V0), "V0");
+        assert_eq!(reg_name(&Register::VA), "VA");
+        assert_eq!(reg_name(&Register::I), "I");
+    }
+}
+
+//end of file
@@ -0,0 +1,260 @@
+/*
+ * sigils.rs
+ * Defines a struct that holds the characters that mark comments
+ * and literals
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//usage statements
+use super::constants;
+use super::super::error::SigilError;
+
+/// The characters that already have a meaning in the syntax,
+/// so they cannot start a literal
+const RESERVED_CHARS: &str = ",.:_()+-*/\"\\";
+
+/// The markers that start comments and literals in source code
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Sigils {
+    /// The text that starts a comment
+    comment: String,
+
+    /// The character that starts a decimal literal
+    dec: char,
+
+    /// The character that starts a hex literal
+    hex: char,
+
+    /// The character that starts a binary literal
+    bin: char
+}
+
+//implementation
+impl Sigils {
+    /// Constructs a new `Sigils` instance
+    /// with the default markers
+    ///
+    /// # Returns
+    ///
+    /// A new `Sigils` instance that uses the markers in `constants`
+    pub fn new() -> Self {
+        return Sigils {
+            comment: constants::COMMENT_CHAR.to_string(),
+            dec: constants::DEC_LIT_CHAR,
+            hex: constants::HEX_LIT_CHAR,
+            bin: constants::BIN_LIT_CHAR
+        };
+    }
+
+    /// Sets the text that starts a comment
+    ///
+    /// An empty marker is ignored, since it would
+    /// turn the whole source into a comment
+    ///
+    /// # Argument
+    ///
+    /// * `new_comment` - The new comment marker, such as `//`
+    ///
+    /// # Returns
+    ///
+    /// The sigils with the new comment marker
+    pub fn with_comment(mut self, new_comment: &str) -> Self {
+        if !new_comment.is_empty() {
+            self.comment = String::from(new_comment);
+        }
+        return self;
+    }
+
+    /// Sets the character that starts a decimal literal
+    ///
+    /// # Argument
+    ///
+    /// * `new_dec` - The new decimal sigil
+    ///
+    /// # Returns
+    ///
+    /// The sigils with the new decimal sigil
+    pub fn with_dec(mut self, new_dec: char) -> Self {
+        self.dec = new_dec;
+        return self;
+    }
+
+    /// Sets the character that starts a hex literal
+    ///
+    /// # Argument
+    ///
+    /// * `new_hex` - The new hex sigil
+    ///
+    /// # Returns
+    ///
+    /// The sigils with the new hex sigil
+    pub fn with_hex(mut self, new_hex: char) -> Self {
+        self.hex = new_hex;
+        return self;
+    }
+
+    /// Sets the character that starts a binary literal
+    ///
+    /// # Argument
+    ///
+    /// * `new_bin` - The new binary sigil
+    ///
+    /// # Returns
+    ///
+    /// The sigils with the new binary sigil
+    pub fn with_bin(mut self, new_bin: char) -> Self {
+        self.bin = new_bin;
+        return self;
+    }
+
+    /// Gets the text that starts a comment
+    ///
+    /// # Returns
+    ///
+    /// The comment marker
+    pub fn get_comment(&self) -> &str {
+        return &self.comment;
+    }
+
+    /// Gets the character that starts a decimal literal
+    ///
+    /// # Returns
+    ///
+    /// The decimal sigil
+    pub fn get_dec(&self) -> char {
+        return self.dec;
+    }
+
+    /// Gets the character that starts a hex literal
+    ///
+    /// # Returns
+    ///
+    /// The hex sigil
+    pub fn get_hex(&self) -> char {
+        return self.hex;
+    }
+
+    /// Gets the character that starts a binary literal
+    ///
+    /// # Returns
+    ///
+    /// The binary sigil
+    pub fn get_bin(&self) -> char {
+        return self.bin;
+    }
+
+    /// Checks that every marker can be told apart from the others
+    /// and from the rest of the syntax
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the markers can be used together, or a `SigilError`
+    /// naming the first marker that cannot
+    pub fn validate(&self) -> Result<(), SigilError> {
+        //the literal sigils must be punctuation that means nothing
+        //else, and must all differ
+        let lits = [("decimal", self.dec), ("hex", self.hex),
+                    ("binary", self.bin)];
+        for (i, &(name, sigil)) in lits.iter().enumerate() {
+            if !sigil.is_ascii_punctuation() ||
+                RESERVED_CHARS.contains(sigil) {
+                return Err(SigilError::new(&sigil.to_string(), &format!(
+                    "the {} sigil is already part of the syntax", name)));
+            }
+            if let Some(&(other, _)) = lits[..i].iter()
+                                            .find(|&&(_, s)| s == sigil) {
+                return Err(SigilError::new(&sigil.to_string(), &format!(
+                    "the {} sigil is also the {} sigil", name, other)));
+            }
+        }
+
+        //and the comment marker must not start like a literal or a word
+        let first = self.comment.chars().next().unwrap_or(' ');
+        if first.is_alphanumeric() || first.is_whitespace() {
+            return Err(SigilError::new(&self.comment,
+                        "a comment marker must start with punctuation"));
+        }
+        if let Some(&(name, _)) = lits.iter().find(|&&(_, s)| s == first) {
+            return Err(SigilError::new(&self.comment, &format!(
+                "the comment marker starts with the {} sigil", name)));
+        }
+        return Ok(());
+    }
+}
+
+//Default implementation
+impl Default for Sigils {
+    fn default() -> Self {
+        return Sigils::new();
+    }
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the struct
+    use super::*;
+
+    //this test checks that the defaults match the constants
+    #[test]
+    fn test_defaults() {
+        let sig = Sigils::new();
+        assert_eq!(sig.get_comment(), ";");
+        assert_eq!(sig.get_dec(), '#');
+        assert_eq!(sig.get_hex(), '$');
+        assert_eq!(sig.get_bin(), '%');
+    }
+
+    //this test checks that an empty comment marker is ignored
+    #[test]
+    fn test_empty_comment() {
+        let sig = Sigils::new().with_comment("//").with_comment("");
+        assert_eq!(sig.get_comment(), "//");
+    }
+
+    //this test checks validating the markers
+    #[test]
+    fn test_validate() {
+        assert!(Sigils::new().validate().is_ok());
+        assert!(Sigils::new().with_comment("//").with_hex('&')
+                             .validate().is_ok());
+        for (sig, msg) in [
+            (Sigils::new().with_hex('#'),
+             "Invalid marker #: the hex sigil is also the decimal sigil"),
+            (Sigils::new().with_bin('$'),
+             "Invalid marker $: the binary sigil is also the hex sigil"),
+            (Sigils::new().with_comment("$"),
+             "Invalid marker $: the comment marker starts with the hex sigil"),
+            (Sigils::new().with_comment("#!"), "Invalid marker #!: the \
+              comment marker starts with the decimal sigil"),
+            (Sigils::new().with_comment("REM"), "Invalid marker REM: a \
+              comment marker must start with punctuation"),
+            (Sigils::new().with_dec('-'),
+             "Invalid marker -: the decimal sigil is already part of the \
+              syntax"),
+            (Sigils::new().with_hex('x'),
+             "Invalid marker x: the hex sigil is already part of the syntax")
+        ] {
+            let err = sig.validate().err().unwrap();
+            assert_eq!(format!("{}", err), msg);
+        }
+    }
+}
+
+//end of file
@@ -0,0 +1,64 @@
+/*
+ * skip_name.rs
+ * Defines a function that gets the source spelling of a skip condition
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//crate import
+extern crate ch8_isa;
+
+//usage statements
+use ch8_isa::data::SkipType;
+use super::constants;
+
+/// Gets the name of a skip condition as it is written in source code
+///
+/// # Argument
+///
+/// * `st` - The skip condition to name
+///
+/// # Returns
+///
+/// The name of the condition, such as `EQ` or `KD`
+pub fn skip_name(st: &SkipType) -> &'static str {
+    return match *st {
+        SkipType::Equals => constants::SKIP_CONDS[0],
+        SkipType::NotEquals => constants::SKIP_CONDS[1],
+        SkipType::KeyDown => constants::SKIP_CONDS[2],
+        SkipType::KeyUp => constants::SKIP_CONDS[3]
+    };
+}
+
+//unit tests
+#[cfg(test)]
+mod tests {
+    //import the skip_name function
+    use super::*;
+
+    //this test checks naming skip conditions
+    #[test]
+    fn test_skip_name() {
+        assert_eq!(skip_name(&SkipType::Equals), "EQ");
+        assert_eq!(skip_name(&SkipType::NotEquals), "NE");
+        assert_eq!(skip_name(&SkipType::KeyDown), "KD");
+        assert_eq!(skip_name(&SkipType::KeyUp), "KU");
+    }
+}
+
+//end of file
@@ -22,10 +22,14 @@
 
 //usage statements
 use std::cmp;
+use std::fmt;
+use std::hash;
 use super::super::error::VariantError;
+use super::constants;
+use serde::Serialize;
 
 /// Contains a single variable of various types
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub enum Variant {
     /// A single byte 
     Byte(u8),
@@ -80,6 +84,21 @@ impl Variant {
         };
     }
 
+    /// Borrows the text value of the `Variant`, without
+    /// copying it like `as_text` does
+    ///
+    /// # Returns
+    ///
+    /// A reference to the text value of the `Variant`,
+    /// wrapped in a `Result`
+    pub fn as_text_ref(&self) -> Result<&str, VariantError> {
+        return match *self {
+            Variant::Text(ref t) => Ok(t.as_str()),
+            Variant::Byte(_b) => Err(VariantError::new("text", "byte")),
+            Variant::Word(_w) => Err(VariantError::new("text", "word"))
+        };
+    }
+
 }
 
 //PartialEq implementation
@@ -88,19 +107,19 @@ impl cmp::PartialEq for Variant {
         return match *self {
             Variant::Byte(ref b1) => {
                 match *rhs {
-                    Variant::Byte(ref b2) => (b1 == b2),
+                    Variant::Byte(ref b2) => b1 == b2,
                     _ => false
                 }
             },
             Variant::Word(ref w1) => {
                 match *rhs {
-                    Variant::Word(ref w2) => (w1 == w2),
+                    Variant::Word(ref w2) => w1 == w2,
                     _ => false 
                 }
             },
             Variant::Text(ref t1) => {
                 match *rhs {
-                    Variant::Text(ref t2) => (t1 == t2),
+                    Variant::Text(ref t2) => t1 == t2,
                     _ => false 
                 }
             }
@@ -108,12 +127,69 @@ impl cmp::PartialEq for Variant {
     }
 }
 
+//Eq implementation
+impl cmp::Eq for Variant {}
+
+//Hash implementation
+//(the type is hashed along with the value, so that a byte
+//and a word with the same value hash differently, just as
+//they compare unequal)
+impl hash::Hash for Variant {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        match *self {
+            Variant::Byte(b) => {
+                0u8.hash(state);
+                b.hash(state);
+            },
+            Variant::Word(w) => {
+                1u8.hash(state);
+                w.hash(state);
+            },
+            Variant::Text(ref t) => {
+                2u8.hash(state);
+                t.hash(state);
+            }
+        }
+    }
+}
+
+//Display implementation
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Variant::Byte(b) => write!(f, "{}{:02X}",
+                                       constants::HEX_LIT_CHAR, b),
+            Variant::Word(w) => write!(f, "{}{:04X}",
+                                       constants::HEX_LIT_CHAR, w),
+            Variant::Text(ref t) => write!(f, "{}", t)
+        }
+    }
+}
+
 //unit tests
 #[cfg(test)]
 mod tests {
     //import the Variant enum
     use super::*;
 
+    //this test checks using variants as map keys
+    #[test]
+    fn test_map_keys() {
+        let mut map = std::collections::HashMap::new();
+        map.insert(Variant::Text(String::from("_loop")), 1);
+        map.insert(Variant::Byte(0x12), 2);
+        map.insert(Variant::Word(0x12), 3);
+        assert_eq!(map.get(&Variant::Text(String::from("_loop"))), Some(&1));
+        assert_eq!(map.get(&Variant::Byte(0x12)), Some(&2));
+        assert_eq!(map.get(&Variant::Word(0x12)), Some(&3));
+        assert_eq!(map.get(&Variant::Text(String::from("_end"))), None);
+
+        //equal keys replace each other
+        map.insert(Variant::Byte(0x12), 4);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&Variant::Byte(0x12)), Some(&4));
+    }
+
     //this test checks equality comparisons
     #[test]
     fn test_equality() {
@@ -142,6 +218,18 @@ mod tests {
         assert_eq!(v2.as_word().unwrap(), 0xFC00);
         let v3 = Variant::Text(String::from("Hello"));
         assert_eq!(v3.as_text().unwrap(), String::from("Hello"));
+        assert_eq!(v3.as_text_ref().unwrap(), "Hello");
+        assert!(v1.as_text_ref().is_err());
+        assert!(v2.as_text_ref().is_err());
+    }
+
+    //this test checks displaying Variants
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Variant::Byte(0x0F)), "$0F");
+        assert_eq!(format!("{}", Variant::Word(0x0200)), "$0200");
+        assert_eq!(format!("{}", Variant::Text(String::from("_loop"))),
+                   "_loop");
     }
 }
 
@@ -0,0 +1,91 @@
+/*
+ * assemble.rs
+ * Integration tests that use ch8alib as a standalone library
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//crate import
+extern crate ch8alib;
+
+//usage statements
+use ch8alib::codegen::assemble_full;
+use ch8alib::codegen::Assembler;
+use ch8alib::codegen::AsmOptions;
+use ch8alib::codegen::Disassembler;
+use ch8alib::error::AsmError;
+
+//a program that uses labels, aliases, and data
+const CODE: &str = "_start:
+                        CLS
+                        X REG V0
+                        MOV X, #0
+                        MOV I, _sprite
+                    .loop:
+                        DRAW X, V1, #2
+                        ADD X, #8
+                        JMP .loop
+                    _sprite:
+                        DB %11111111, %10000001";
+
+//the bytes that CODE assembles into
+const BYTES: [u8; 14] = [0x00, 0xE0, 0x60, 0x00, 0xA2, 0x0C, 0xD0, 0x12,
+                         0x70, 0x08, 0x12, 0x06, 0xFF, 0x81];
+
+//this test checks assembling a string in memory, end to end
+#[test]
+fn test_assemble_string() {
+    let mut asm = Assembler::unnamed(CODE, &AsmOptions::new()).unwrap();
+    asm.assemble().unwrap();
+    assert_eq!(asm.get_bytes(), &BYTES);
+    assert_eq!(asm.get_addrs().get_entry("_sprite").unwrap(), 0x020C);
+    assert!(asm.get_warnings().is_empty());
+}
+
+//this test checks assembling a string in one call
+#[test]
+fn test_assemble_full() {
+    let (bytes, addrs) = assemble_full(CODE).unwrap();
+    assert_eq!(bytes, BYTES);
+    assert_eq!(addrs.get_entry("_start").unwrap(), 0x0200);
+}
+
+//this test checks that errors come back as values
+#[test]
+fn test_errors() {
+    let mut asm = Assembler::unnamed("CLS\nFOO V1\nDRAW I, V1, #1",
+                                     &AsmOptions::new()).unwrap();
+    let errs = asm.assemble_all().err().unwrap();
+    assert_eq!(errs.len(), 2);
+    match errs[0] {
+        AsmError::Opcode(_) => {},
+        ref e => panic!("unexpected error {}", e)
+    }
+    assert_eq!(errs[0].get_line(), Some(2));
+}
+
+//this test checks that disassembled output assembles again
+#[test]
+fn test_round_trip() {
+    let text = Disassembler::new(&BYTES).disassemble();
+    let mut asm = Assembler::unnamed(&text, &AsmOptions::new()).unwrap();
+    asm.assemble().unwrap();
+    assert_eq!(asm.get_bytes(), &BYTES);
+}
+
+//end of file
@@ -0,0 +1,39 @@
+; golden.asm
+; A fixture program for the golden-file regression test.
+; The expected output is stored next to it in golden.c8; if a change
+; to the assembler is meant to alter the output, regenerate it with
+; `ch8asm golden.asm` (after deleting the old golden.c8).
+
+X REG V0
+Y REG V1
+
+_start:
+    CLS
+    MOV X, #8
+    MOV Y, #4
+    MOV I, _sprite
+.loop:
+    DRAW X, Y, #5
+    ADD X, #9
+    SKIP.EQ X, #62
+    JMP .loop
+    CALL _wait
+    JMP _start
+
+_wait:
+    MOV V2, $3C
+    MOV DELAY, V2
+.spin:
+    MOV V2, DELAY
+    SKIP.EQ V2, #0
+    JMP .spin
+    RET
+
+.data
+_sprite:
+    DB %11110000, %10010000, %11110000, %10010000, %10010000
+_title:
+    DB "CH8;ASM", #0
+    ALIGN
+_table:
+    DW _start, _wait, _sprite + #1
@@ -0,0 +1,50 @@
+/*
+ * golden.rs
+ * Checks the assembler's output against stored binaries
+ * Created on 10/17/2026
+ * Created by Andrew Davis
+ *
+ * Copyright (C) 2026  Andrew Davis
+ *
+ * This program is free software: you can redistribute it and/or modify   
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ * 
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//crate import
+extern crate ch8alib;
+
+//usage statements
+use ch8alib::codegen::assemble_to_vec;
+
+//the fixture source and the binary it is expected to assemble into
+//(regenerate golden.c8 with ch8asm only when a change to the
+//output is intended)
+const GOLDEN_SRC: &str = include_str!("fixtures/golden.asm");
+const GOLDEN_BIN: &[u8] = include_bytes!("fixtures/golden.c8");
+
+//this test checks that the fixture still assembles byte for byte
+//into the stored binary
+#[test]
+fn test_golden() {
+    let bytes = assemble_to_vec(GOLDEN_SRC).unwrap();
+
+    //report the first difference, which is easier to
+    //act on than a dump of both binaries
+    if let Some(i) = bytes.iter().zip(GOLDEN_BIN).position(|(a, b)| a != b) {
+        panic!("byte {} (address ${:03X}) is ${:02X}, expected ${:02X}",
+               i, 0x200 + i, bytes[i], GOLDEN_BIN[i]);
+    }
+    assert_eq!(bytes.len(), GOLDEN_BIN.len(), "binary length changed");
+}
+
+//end of file
@@ -20,85 +20,392 @@
  * along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-//crate import statement
+//lint configuration (explicit returns are house style)
+#![allow(clippy::needless_return)]
+
+//crate import statements
 extern crate ch8alib;
+extern crate serde_json;
 
 //usage statements
 use std::env;
 use std::path::Path;
 use std::fs;
+use std::process::ExitCode;
 use ch8alib::codegen::Assembler;
+use ch8alib::codegen::AsmOptions;
+use ch8alib::lex::AsmLexer;
+use ch8alib::lex::TokenType;
+use ch8alib::codegen::to_ihex;
+use ch8alib::codegen::hex_dump;
 use ch8alib::util::conv_filename;
-use ch8alib::util::constants;
+use ch8alib::util::Sigils;
+use ch8alib::util::decode_source;
+use ch8alib::error::AsmError;
+use ch8alib::error::AsmWarning;
+use ch8alib::error::Diagnostic;
 
 //Entry point for the program
-fn main() {
+//(exits with a failure status if any file could not be assembled)
+fn main() -> ExitCode {
     //get the args list
     let args: Vec<String> = env::args().collect();
 
-    //determine whether an argument was supplied
-    if args.len() != 2 {
-        println!("Usage: {} <filename>", args[0]);
-        return;
+    //split the arguments into options and source files
+    let mut flags = Flags::default();
+    let mut format = "bin";
+    let mut comment = "";
+    let mut files = Vec::new();
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--listing" => flags.listing = true,
+            "--dump" => flags.dump = true,
+            "--symbols" => flags.symbols = true,
+            "--check" => flags.check = true,
+            "--emit-tokens" => flags.emit_tokens = true,
+            "--verbose" => flags.verbose = true,
+            "--check-calls" => flags.check_calls = true,
+            "--check-entry" => flags.check_entry = true,
+            "--stats" => flags.stats = true,
+            "--strict" => flags.strict = true,
+            "--werror" => flags.werror = true,
+            "--diagnostics=json" => flags.json = true,
+            "--no-preprocess" => flags.no_preprocess = true,
+            "--format" => format = iter.next().map_or("", |f| f.as_str()),
+            "--comment" => comment = iter.next().map_or("", |c| c.as_str()),
+            _ => files.push(arg.as_str())
+        };
+    }
+    flags.format = String::from(format);
+    flags.comment = String::from(comment);
+
+    //determine whether any source files were supplied
+    if files.is_empty() || ((format != "bin") && (format != "ihex")) {
+        println!("Usage: {} [--check] [--emit-tokens] [--verbose] \
+                  [--check-calls] [--check-entry] [--strict] [--werror] \
+                  [--diagnostics=json] [--no-preprocess] \
+                  [--stats] [--listing] [--symbols] [--dump] \
+                  [--format bin|ihex] [--comment <marker>] \
+                  <filename>...", args[0]);
+        return ExitCode::FAILURE;
+    }
+
+    //assemble each file, carrying on past any that fail
+    let mut status = ExitCode::SUCCESS;
+    let mut diags = Vec::new();
+    for src in files {
+        if assemble_file(src, &flags, &mut diags) != ExitCode::SUCCESS {
+            eprintln!("Failed to assemble {}", src);
+            status = ExitCode::FAILURE;
+        }
     }
 
-    //get the filename
-    let src = args[1].as_str();
+    //print the diagnostics of every file as one JSON array
+    if flags.json && !flags.emit_tokens {
+        println!("{}", serde_json::Value::Array(diags));
+    }
+
+    //and return the overall status
+    return status;
+}
+
+//The command-line options that apply to every source file
+#[derive(Default)]
+struct Flags {
+    //whether to write a listing file
+    listing: bool,
+
+    //whether to print a hex dump
+    dump: bool,
+
+    //whether to write a symbol file
+    symbols: bool,
+
+    //whether to only check the syntax
+    check: bool,
+
+    //whether to print the tokens instead of assembling
+    emit_tokens: bool,
+
+    //whether to print the address and bytes of each line
+    verbose: bool,
+
+    //whether to print a summary of the assembled program
+    stats: bool,
+
+    //whether to check the calls and returns
+    check_calls: bool,
+
+    //whether to check that the program starts with an instruction
+    check_entry: bool,
+
+    //whether stray tokens are errors
+    strict: bool,
+
+    //whether any warning makes the assembly fail
+    werror: bool,
+
+    //whether to report errors and warnings as JSON
+    json: bool,
+
+    //whether to skip the preprocessor (a debugging aid)
+    no_preprocess: bool,
 
+    //the output format (bin or ihex)
+    format: String,
+
+    //the text that starts a comment (empty for the default)
+    comment: String
+}
+
+//Assembles a single source file with the given options,
+//adding its diagnostics to diags if they are reported as JSON
+//(returns a failure status if anything goes wrong)
+fn assemble_file(src: &str, flags: &Flags,
+                 diags: &mut Vec<serde_json::Value>) -> ExitCode {
     //ensure that it exists
     if !Path::new(src).exists() {
-        println!("Couldn't open {}", src);
-        return;
+        eprintln!("Couldn't open {}", src);
+        return ExitCode::FAILURE;
     }
 
-    //read the file into a string
-    let code = match fs::read_to_string(src) {
+    //read the file
+    //(it need not be UTF-8, since comments may hold any bytes)
+    let code = match fs::read(src) {
         Ok(c) => c,
         Err(e) => {
             eprintln!("{}", e);
-            return;
+            return ExitCode::FAILURE;
         }
     };
 
+    //get the comment and literal markers
+    let sigils = Sigils::new().with_comment(&flags.comment);
+
+    //print the tokens instead of assembling if they were requested
+    //(the assembler checks the markers itself, but the lexer does not)
+    if flags.emit_tokens {
+        if let Err(e) = sigils.validate() {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+        return print_tokens(&decode_source(&code), &sigils);
+    }
+
     //get the name of the assembled binary
-    let asm_name = conv_filename(src);
+    //(a syntax check never writes the binary, so it needs no name)
+    let mut asm_name = if flags.check {
+        String::new()
+    } else {
+        conv_filename(src)
+    };
 
     //create the assembler
-    let mut asm = match Assembler::new(code.as_str(), 
-                                        asm_name.as_str()) {
+    let opts = AsmOptions::new().with_strict(flags.strict)
+                                .with_sigils(&sigils)
+                                .with_preprocess(!flags.no_preprocess);
+    let mut asm = match Assembler::from_bytes(&code, asm_name.as_str(),
+                                              &opts) {
         Ok(a) => a,
         Err(e) =>  {
-            eprintln!("{}", e);
-            return;
+            report(&[e], &[], src, flags, diags);
+            return ExitCode::FAILURE;
         }
     };
 
-    //assemble the code
-    let bin = match asm.assemble() {
-        Ok(b) => b,
-        Err(e) => {
+    //assemble the code, reporting every error found
+    //(the binary is written once the warnings have been checked)
+    if let Err(errs) = asm.assemble_all() {
+        report(&errs, asm.get_warnings(), src, flags, diags);
+        return ExitCode::FAILURE;
+    }
+
+    //check the calls and returns if it was requested
+    if flags.check_calls {
+        asm.check_calls();
+    }
+
+    //check the start of the program if it was requested
+    if flags.check_entry {
+        asm.check_entry();
+    }
+
+    //report any warnings
+    report(&[], asm.get_warnings(), src, flags, diags);
+
+    //and fail without writing anything if warnings are errors
+    if flags.werror && !asm.get_warnings().is_empty() {
+        eprintln!("{} warning(s) treated as errors",
+                  asm.get_warnings().len());
+        return ExitCode::FAILURE;
+    }
+
+    //write the output in the requested format
+    //(a syntax check never writes anything)
+    if !flags.check && (flags.format == "ihex") {
+        let hex_name = Path::new(&asm_name).with_extension("hex");
+        let hex = to_ihex(asm.get_bytes(), asm.get_origin());
+        if let Err(e) = fs::write(&hex_name, hex) {
             eprintln!("{}", e);
-            return;
+            return ExitCode::FAILURE;
         }
-    };
+        asm_name = hex_name.to_string_lossy().into_owned();
+    } else if !flags.check {
+        if let Err(e) = asm.write() {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    }
 
-    //write the binary to its destination
-    match bin.write_to_file() {
-        Ok(n) => {
-            if (n as u16 + constants::MEM_START) >= constants::MEM_SIZE {
-                eprintln!("Binary is too large ({} bytes)", n);
-                fs::remove_file(asm_name).unwrap();
-                return;
+    //print the address and bytes of each assembled line
+    //if it was requested
+    if flags.verbose {
+        for (addr, bytes, line) in asm.get_listing().iter() {
+            if !bytes.is_empty() {
+                let hex: String = bytes.iter()
+                                       .map(|b| format!("{:02X}", b))
+                                       .collect();
+                show(&format!("{:04X}: {:<4}  {}\n", addr, hex, line),
+                     flags);
             }
-        },
-        Err(e) => {
+        }
+    }
+
+    //print a summary of the program if it was requested
+    if flags.stats {
+        show(&format_stats(&asm), flags);
+    }
+
+    //stop here if only checking the syntax
+    //(JSON diagnostics are the only output for tools)
+    if flags.check {
+        if !flags.json {
+            println!("{}: OK", src);
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    //print a hex dump if it was requested
+    if flags.dump {
+        show(&hex_dump(asm.get_bytes(), asm.get_origin()), flags);
+    }
+
+    //write the listing if it was requested
+    if flags.listing {
+        let lst_name = Path::new(&asm_name).with_extension("lst");
+        if let Err(e) = fs::write(&lst_name, asm.get_listing().to_string()) {
             eprintln!("{}", e);
-            return;
+            return ExitCode::FAILURE;
         }
-    };
+    }
+
+    //write the symbol file if it was requested
+    if flags.symbols {
+        let sym_name = Path::new(&asm_name).with_extension("sym");
+        let syms: String = asm.get_addrs().iter()
+                        .map(|(lbl, addr)| format!("{:04X} {}\n", addr, lbl))
+                        .collect();
+        if let Err(e) = fs::write(&sym_name, syms) {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    }
 
     //and print out a success message
-    println!("Successfully assembled {} into {}", src, asm_name);
+    if !flags.json {
+        println!("Successfully assembled {} into {} ({} bytes)", src,
+                 asm_name, asm.len());
+    }
+    return ExitCode::SUCCESS;
+}
+
+//Reports errors and warnings, either as text on stderr or,
+//if diagnostics were requested as JSON, by adding them to diags
+//with the file, line, col, severity, and message of each one
+fn report(errs: &[AsmError], warns: &[AsmWarning], src: &str,
+          flags: &Flags, diags: &mut Vec<serde_json::Value>) {
+    if flags.json {
+        let found = errs.iter()
+                        .map(Diagnostic::from_error)
+                        .chain(warns.iter().map(Diagnostic::from_warning));
+        for diag in found {
+            //add the file of the problem to its JSON form
+            let mut json = serde_json::to_value(&diag).unwrap();
+            json["file"] = serde_json::Value::from(src);
+            diags.push(json);
+        }
+    } else {
+        for e in errs {
+            eprintln!("{}", e);
+        }
+        for w in warns {
+            eprintln!("{}", w);
+        }
+    }
+}
+
+//Prints output other than diagnostics, on stdout or, if diagnostics
+//are reported as JSON, on stderr so that stdout holds only the JSON
+fn show(text: &str, flags: &Flags) {
+    if flags.json {
+        eprint!("{}", text);
+    } else {
+        print!("{}", text);
+    }
+}
+
+//Formats the number of instructions and data bytes in an
+//assembled program, along with its size and where it is loaded
+fn format_stats(asm: &Assembler) -> String {
+    let instr_bytes = asm.get_code_len();
+    let mut ret = format!("Instructions: {}\n", asm.get_instr_count());
+    ret += &format!("Data bytes: {}\n", asm.len() - instr_bytes);
+    ret += &format!("Total size: {} bytes\n", asm.len());
+    if asm.is_empty() {
+        ret += "Addresses: none\n";
+    } else {
+        ret += &format!("Addresses: ${:03X}-${:03X}\n", asm.get_origin(),
+                        asm.get_address() - 1);
+    }
+    return ret;
+}
+
+//Prints the tokens in a source file as a JSON array,
+//giving the type, value, line, and column of each token
+//(lexer errors are reported and skipped, and cause a failure status)
+fn print_tokens(code: &str, sigils: &Sigils) -> ExitCode {
+    //create the lexer
+    let mut lex = AsmLexer::with_sigils(code, sigils);
+
+    //loop and collect the tokens
+    let mut toks = Vec::new();
+    let mut status = ExitCode::SUCCESS;
+    loop {
+        let tok = match lex.get_next_token() {
+            Ok(t) => t,
+            Err(e) => {
+                eprintln!("{}", e);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        //add the position of the token to its JSON form
+        let mut json = serde_json::to_value(&tok).unwrap();
+        json["line"] = serde_json::Value::from(lex.get_token_line());
+        json["col"] = serde_json::Value::from(lex.get_token_column());
+        toks.push(json);
+
+        //and stop after the end of the input
+        if tok.get_type() == TokenType::EndOfInput {
+            break;
+        }
+    }
+
+    //and print the tokens
+    println!("{}", serde_json::Value::Array(toks));
+    return status;
 }
 
 //end of file